@@ -73,6 +73,7 @@ pub async fn validate_fusion(
         name: "Metadata Completeness".to_string(),
         passed: fusion.metadata.completeness >= 0.5,
         score: Some(fusion.metadata.completeness),
+        weight: 1.0,
         message: if fusion.metadata.completeness < 0.5 {
             Some(format!(
                 "Metadata is incomplete ({:.0}% complete)",
@@ -89,6 +90,7 @@ pub async fn validate_fusion(
         name: "Flavor Completeness".to_string(),
         passed: fusion.flavor.completeness >= 0.5,
         score: Some(fusion.flavor.completeness),
+        weight: 1.0,
         message: if fusion.flavor.completeness < 0.5 {
             Some(format!(
                 "Musical flavor is incomplete ({:.0}% of expected characteristics)",
@@ -105,6 +107,8 @@ pub async fn validate_fusion(
         name: "Identity Confidence".to_string(),
         passed: fusion.identity.confidence >= 0.7,
         score: Some(fusion.identity.confidence),
+        // Recording identity (MBID) is the most load-bearing check - weight it highest
+        weight: 2.0,
         message: if fusion.identity.confidence < 0.7 {
             Some(format!(
                 "Identity confidence is low ({:.0}%)",
@@ -132,7 +136,10 @@ pub async fn validate_fusion(
     }
 
     // REQ-AI-064: Calculate overall quality score
-    let (quality_score, status) = quality_scorer::calculate_quality_score(&checks);
+    let (quality_score, status) = quality_scorer::calculate_quality_score(
+        &checks,
+        &quality_scorer::QualityScoreThresholds::default(),
+    );
 
     debug!(
         "Validation complete: {} checks, quality={:.1}%, status={:?}",
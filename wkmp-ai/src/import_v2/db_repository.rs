@@ -5,124 +5,95 @@
 // into the 21 PLAN023 columns added by migration v3.
 //
 // Requirements: REQ-AI-081 through REQ-AI-087
-
-use crate::import_v2::types::{ExtractionSource, ProcessedPassage};
-use serde_json::json;
+//
+// **[REQ-AI-088]** Persistence is accessed through the `PassageRepository` trait so
+// `session_orchestrator` and Tier 3 tests can swap the concrete storage engine
+// (SQLite, in-memory, journaled) without knowing which one is in use.
+
+use crate::import_v2::types::{ExtractionSource, ProcessedPassage, ValidationReport};
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 use tracing::info;
 use uuid::Uuid;
 
-/// Database repository for PLAN023 import data
-pub struct ImportRepository {
-    pool: SqlitePool,
+/// Backend-agnostic view of a persisted passage record
+///
+/// Mirrors the subset of `passages` columns every backend can answer without
+/// re-parsing the full PLAN023 provenance blob.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassageRecord {
+    pub passage_id: Uuid,
+    pub file_id: Uuid,
+    pub import_session_id: Uuid,
+    pub recording_mbid: Option<Uuid>,
+    pub title: Option<String>,
+    pub validation_status: String,
+    pub overall_quality_score: f64,
 }
 
-impl ImportRepository {
-    /// Create new repository with database pool
-    pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
-    }
-
-    /// Save processed passage to database
-    ///
-    /// **[REQ-AI-081 through REQ-AI-087]** Store complete provenance data
-    ///
-    /// Inserts a new passage record with all PLAN023 columns populated.
-    /// Uses a transaction to ensure atomicity with provenance log entries.
-    pub async fn save_processed_passage(
+/// Storage backend for `ProcessedPassage` persistence
+///
+/// **[REQ-AI-088]** Implementations must not assume a SQLite pool is available -
+/// `session_orchestrator` is constructed with an `Arc<dyn PassageRepository>`
+/// chosen at startup from configuration, so Tier 3 and session-level tests can
+/// run against `InMemoryPassageRepository` without touching disk.
+#[async_trait]
+pub trait PassageRepository: Send + Sync {
+    /// Insert a new processed passage, returning its generated `passage_id`
+    async fn save_processed_passage(
         &self,
         file_id: &Uuid,
         processed: &ProcessedPassage,
         import_session_id: &Uuid,
-    ) -> Result<Uuid, sqlx::Error> {
-        let mut tx = self.pool.begin().await?;
-        let passage_id = Uuid::new_v4();
+    ) -> Result<Uuid>;
 
-        // Serialize complex types to JSON
-        let identity_conflicts_json = self.serialize_identity_candidates(processed);
+    /// Fetch a single passage record by its `passage_id`
+    async fn fetch_by_id(&self, passage_id: &Uuid) -> Result<Option<PassageRecord>>;
 
-        let flavor_source_blend_json = self.serialize_flavor_sources(processed);
-        let musical_flavor_json = self.serialize_musical_flavor(processed);
-        let validation_report_json = self.serialize_validation(processed);
+    /// Fetch all passage records resolved to a given MusicBrainz recording MBID
+    async fn fetch_by_mbid(&self, mbid: &Uuid) -> Result<Vec<PassageRecord>>;
 
-        // Insert passage with all PLAN023 columns
-        sqlx::query(
-            r#"
-            INSERT INTO passages (
-                guid, file_id, start_time_ticks, end_time_ticks,
-                recording_mbid, identity_confidence, identity_conflicts,
-                title, title_source, title_confidence,
-                artist, artist_source, artist_confidence,
-                album, album_source, album_confidence,
-                musical_flavor_vector, flavor_source_blend, flavor_confidence_map,
-                overall_quality_score, metadata_completeness, flavor_completeness,
-                validation_status, validation_report,
-                import_session_id, import_timestamp, import_strategy,
-                import_duration_ms, import_version
-            )
-            VALUES (
-                ?, ?, ?, ?,
-                ?, ?, ?,
-                ?, ?, ?,
-                ?, ?, ?,
-                ?, ?, ?,
-                ?, ?, ?,
-                ?, ?, ?,
-                ?, ?,
-                ?, ?, ?,
-                ?, ?
-            )
-            "#,
-        )
-        .bind(passage_id.to_string())
-        .bind(file_id.to_string())
-        .bind(processed.boundary.start_ticks)
-        .bind(processed.boundary.end_ticks)
-        // Identity (REQ-AI-083)
-        .bind(processed.identity.mbid.as_ref().map(|u| u.to_string()))
-        .bind(processed.identity.confidence)
-        .bind(&identity_conflicts_json)
-        // Metadata (REQ-AI-082)
-        .bind(processed.metadata.title.as_ref().map(|f| &f.value))
-        .bind(processed.metadata.title.as_ref().map(|f| source_to_string(&f.source)))
-        .bind(processed.metadata.title.as_ref().map(|f| f.confidence))
-        .bind(processed.metadata.artist.as_ref().map(|f| &f.value))
-        .bind(processed.metadata.artist.as_ref().map(|f| source_to_string(&f.source)))
-        .bind(processed.metadata.artist.as_ref().map(|f| f.confidence))
-        .bind(processed.metadata.album.as_ref().map(|f| &f.value))
-        .bind(processed.metadata.album.as_ref().map(|f| source_to_string(&f.source)))
-        .bind(processed.metadata.album.as_ref().map(|f| f.confidence))
-        // Flavor (REQ-AI-081)
-        .bind(&musical_flavor_json)
-        .bind(&flavor_source_blend_json)
-        .bind(self.serialize_flavor_confidence(processed))
-        // Validation (REQ-AI-084, REQ-AI-085)
-        .bind(processed.validation.quality_score)
-        .bind(self.calculate_metadata_completeness(processed))
-        .bind(processed.flavor.flavor_completeness)
-        .bind(self.validation_status(&processed.validation))
-        .bind(&validation_report_json)
-        // Import metadata (REQ-AI-086)
-        .bind(import_session_id.to_string())
-        .bind(chrono::Utc::now().timestamp())
-        .bind("HybridFusion")
-        .bind(processed.import_duration_ms as i64)
-        .bind(&processed.import_version)
-        .execute(&mut *tx)
-        .await?;
+    /// Delete a passage record, returning whether a record was actually removed
+    async fn delete(&self, passage_id: &Uuid) -> Result<bool>;
 
-        // Create import_provenance entries (REQ-AI-087)
-        self.create_provenance_entries(&mut tx, &passage_id, processed)
-            .await?;
+    /// List the `passage_id`s persisted for a given import session
+    async fn list_for_session(&self, import_session_id: &Uuid) -> Result<Vec<Uuid>>;
+}
 
-        tx.commit().await?;
+/// Derive the PLAN023 `validation_status` string from a validation report
+///
+/// Shared by every backend so "Pass"/"Warning"/"Fail" classification stays
+/// consistent regardless of where the record ends up stored.
+fn derive_validation_status(validation: &ValidationReport) -> String {
+    if validation.has_conflicts {
+        "Fail".to_string()
+    } else if !validation.warnings.is_empty() {
+        "Warning".to_string()
+    } else if validation.quality_score >= 0.8 {
+        "Pass".to_string()
+    } else {
+        "Warning".to_string()
+    }
+}
 
-        info!(
-            "Saved passage {} (file {}) with PLAN023 provenance",
-            passage_id, file_id
-        );
+/// SQLite-backed `PassageRepository` - the persistent, production backend
+///
+/// **[REQ-AI-081 through REQ-AI-087]** Store complete provenance data
+pub struct SqlitePassageRepository {
+    pool: SqlitePool,
+}
 
-        Ok(passage_id)
+impl SqlitePassageRepository {
+    /// Create new repository with database pool
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
     }
 
     /// Create import_provenance log entries
@@ -150,7 +121,7 @@ impl ImportRepository {
             .bind(Uuid::new_v4().to_string())
             .bind(passage_id.to_string())
             .bind("MBIDCandidate")
-            .bind(json!({ "mbid": candidate.mbid, "sources": sources_json }).to_string())
+            .bind(serde_json::json!({ "mbid": candidate.mbid, "sources": sources_json }).to_string())
             .bind(candidate.confidence)
             .bind(timestamp)
             .execute(&mut **tx)
@@ -199,17 +170,17 @@ impl ImportRepository {
     /// Serialize flavor confidence (overall confidence for now)
     fn serialize_flavor_confidence(&self, processed: &ProcessedPassage) -> String {
         // Simple approach: store overall confidence
-        json!({ "overall": processed.flavor.flavor_confidence }).to_string()
+        serde_json::json!({ "overall": processed.flavor.flavor_confidence }).to_string()
     }
 
     /// Serialize validation report
     fn serialize_validation(&self, processed: &ProcessedPassage) -> String {
-        json!({
+        serde_json::json!({
             "quality_score": processed.validation.quality_score,
             "has_conflicts": processed.validation.has_conflicts,
             "warnings": processed.validation.warnings,
             "conflicts": processed.validation.conflicts.iter().map(|(msg, sev)| {
-                json!({ "message": msg, "severity": format!("{:?}", sev) })
+                serde_json::json!({ "message": msg, "severity": format!("{:?}", sev) })
             }).collect::<Vec<_>>()
         })
         .to_string()
@@ -242,19 +213,6 @@ impl ImportRepository {
         filled / total
     }
 
-    /// Determine validation status string
-    fn validation_status(&self, validation: &crate::import_v2::types::ValidationReport) -> String {
-        if validation.has_conflicts {
-            "Fail".to_string()
-        } else if !validation.warnings.is_empty() {
-            "Warning".to_string()
-        } else if validation.quality_score >= 0.8 {
-            "Pass".to_string()
-        } else {
-            "Warning".to_string()
-        }
-    }
-
     /// Serialize identity candidates to JSON
     fn serialize_identity_candidates(&self, processed: &ProcessedPassage) -> String {
         let candidates_json: Vec<serde_json::Value> = processed
@@ -263,7 +221,7 @@ impl ImportRepository {
             .iter()
             .map(|c| {
                 let sources: Vec<String> = c.sources.iter().map(source_to_string).collect();
-                json!({
+                serde_json::json!({
                     "mbid": c.mbid.to_string(),
                     "confidence": c.confidence,
                     "sources": sources
@@ -273,6 +231,390 @@ impl ImportRepository {
 
         serde_json::to_string(&candidates_json).unwrap_or_else(|_| "[]".to_string())
     }
+
+    /// Parse a `passages` row fetched with the common `PassageRecord` column set
+    fn row_to_record(
+        row: (String, String, String, Option<String>, Option<String>, String, f64),
+    ) -> PassageRecord {
+        let (guid, file_id, import_session_id, recording_mbid, title, validation_status, overall_quality_score) = row;
+        PassageRecord {
+            passage_id: Uuid::parse_str(&guid).unwrap_or_default(),
+            file_id: Uuid::parse_str(&file_id).unwrap_or_default(),
+            import_session_id: Uuid::parse_str(&import_session_id).unwrap_or_default(),
+            recording_mbid: recording_mbid.and_then(|m| Uuid::parse_str(&m).ok()),
+            title,
+            validation_status,
+            overall_quality_score,
+        }
+    }
+}
+
+#[async_trait]
+impl PassageRepository for SqlitePassageRepository {
+    /// Save processed passage to database
+    ///
+    /// **[REQ-AI-081 through REQ-AI-087]** Store complete provenance data
+    ///
+    /// Inserts a new passage record with all PLAN023 columns populated.
+    /// Uses a transaction to ensure atomicity with provenance log entries.
+    async fn save_processed_passage(
+        &self,
+        file_id: &Uuid,
+        processed: &ProcessedPassage,
+        import_session_id: &Uuid,
+    ) -> Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let passage_id = Uuid::new_v4();
+
+        // Serialize complex types to JSON
+        let identity_conflicts_json = self.serialize_identity_candidates(processed);
+
+        let flavor_source_blend_json = self.serialize_flavor_sources(processed);
+        let musical_flavor_json = self.serialize_musical_flavor(processed);
+        let validation_report_json = self.serialize_validation(processed);
+
+        // Insert passage with all PLAN023 columns
+        sqlx::query(
+            r#"
+            INSERT INTO passages (
+                guid, file_id, start_time_ticks, end_time_ticks,
+                recording_mbid, identity_confidence, identity_conflicts,
+                title, title_source, title_confidence,
+                artist, artist_source, artist_confidence,
+                album, album_source, album_confidence,
+                musical_flavor_vector, flavor_source_blend, flavor_confidence_map,
+                overall_quality_score, metadata_completeness, flavor_completeness,
+                validation_status, validation_report,
+                import_session_id, import_timestamp, import_strategy,
+                import_duration_ms, import_version
+            )
+            VALUES (
+                ?, ?, ?, ?,
+                ?, ?, ?,
+                ?, ?, ?,
+                ?, ?, ?,
+                ?, ?, ?,
+                ?, ?, ?,
+                ?, ?, ?,
+                ?, ?,
+                ?, ?, ?,
+                ?, ?
+            )
+            "#,
+        )
+        .bind(passage_id.to_string())
+        .bind(file_id.to_string())
+        .bind(processed.boundary.start_ticks)
+        .bind(processed.boundary.end_ticks)
+        // Identity (REQ-AI-083)
+        .bind(processed.identity.mbid.as_ref().map(|u| u.to_string()))
+        .bind(processed.identity.confidence)
+        .bind(&identity_conflicts_json)
+        // Metadata (REQ-AI-082)
+        .bind(processed.metadata.title.as_ref().map(|f| &f.value))
+        .bind(processed.metadata.title.as_ref().map(|f| source_to_string(&f.source)))
+        .bind(processed.metadata.title.as_ref().map(|f| f.confidence))
+        .bind(processed.metadata.artist.as_ref().map(|f| &f.value))
+        .bind(processed.metadata.artist.as_ref().map(|f| source_to_string(&f.source)))
+        .bind(processed.metadata.artist.as_ref().map(|f| f.confidence))
+        .bind(processed.metadata.album.as_ref().map(|f| &f.value))
+        .bind(processed.metadata.album.as_ref().map(|f| source_to_string(&f.source)))
+        .bind(processed.metadata.album.as_ref().map(|f| f.confidence))
+        // Flavor (REQ-AI-081)
+        .bind(&musical_flavor_json)
+        .bind(&flavor_source_blend_json)
+        .bind(self.serialize_flavor_confidence(processed))
+        // Validation (REQ-AI-084, REQ-AI-085)
+        .bind(processed.validation.quality_score)
+        .bind(self.calculate_metadata_completeness(processed))
+        .bind(processed.flavor.flavor_completeness)
+        .bind(derive_validation_status(&processed.validation))
+        .bind(&validation_report_json)
+        // Import metadata (REQ-AI-086)
+        .bind(import_session_id.to_string())
+        .bind(chrono::Utc::now().timestamp())
+        .bind("HybridFusion")
+        .bind(processed.import_duration_ms as i64)
+        .bind(&processed.import_version)
+        .execute(&mut *tx)
+        .await?;
+
+        // Create import_provenance entries (REQ-AI-087)
+        self.create_provenance_entries(&mut tx, &passage_id, processed)
+            .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Saved passage {} (file {}) with PLAN023 provenance",
+            passage_id, file_id
+        );
+
+        Ok(passage_id)
+    }
+
+    async fn fetch_by_id(&self, passage_id: &Uuid) -> Result<Option<PassageRecord>> {
+        let row = sqlx::query_as::<_, (String, String, String, Option<String>, Option<String>, String, f64)>(
+            r#"
+            SELECT guid, file_id, import_session_id, recording_mbid, title, validation_status, overall_quality_score
+            FROM passages WHERE guid = ?
+            "#,
+        )
+        .bind(passage_id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Self::row_to_record))
+    }
+
+    async fn fetch_by_mbid(&self, mbid: &Uuid) -> Result<Vec<PassageRecord>> {
+        let rows = sqlx::query_as::<_, (String, String, String, Option<String>, Option<String>, String, f64)>(
+            r#"
+            SELECT guid, file_id, import_session_id, recording_mbid, title, validation_status, overall_quality_score
+            FROM passages WHERE recording_mbid = ?
+            "#,
+        )
+        .bind(mbid.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Self::row_to_record).collect())
+    }
+
+    async fn delete(&self, passage_id: &Uuid) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM passages WHERE guid = ?")
+            .bind(passage_id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_for_session(&self, import_session_id: &Uuid) -> Result<Vec<Uuid>> {
+        let guids: Vec<String> = sqlx::query_scalar("SELECT guid FROM passages WHERE import_session_id = ?")
+            .bind(import_session_id.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(guids
+            .into_iter()
+            .filter_map(|g| Uuid::parse_str(&g).ok())
+            .collect())
+    }
+}
+
+/// In-memory `PassageRepository` backend for unit tests
+///
+/// Stores records in a process-local map; nothing is persisted to disk. Lets
+/// Tier 3 and `session_orchestrator` tests exercise repository behavior
+/// without a SQLite pool.
+#[derive(Default)]
+pub struct InMemoryPassageRepository {
+    records: Mutex<HashMap<Uuid, PassageRecord>>,
+}
+
+impl InMemoryPassageRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PassageRepository for InMemoryPassageRepository {
+    async fn save_processed_passage(
+        &self,
+        file_id: &Uuid,
+        processed: &ProcessedPassage,
+        import_session_id: &Uuid,
+    ) -> Result<Uuid> {
+        let passage_id = Uuid::new_v4();
+        let record = PassageRecord {
+            passage_id,
+            file_id: *file_id,
+            import_session_id: *import_session_id,
+            recording_mbid: processed.identity.mbid,
+            title: processed.metadata.title.as_ref().map(|f| f.value.clone()),
+            validation_status: derive_validation_status(&processed.validation),
+            overall_quality_score: processed.validation.quality_score,
+        };
+
+        self.records.lock().await.insert(passage_id, record);
+        Ok(passage_id)
+    }
+
+    async fn fetch_by_id(&self, passage_id: &Uuid) -> Result<Option<PassageRecord>> {
+        Ok(self.records.lock().await.get(passage_id).cloned())
+    }
+
+    async fn fetch_by_mbid(&self, mbid: &Uuid) -> Result<Vec<PassageRecord>> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .values()
+            .filter(|r| r.recording_mbid.as_ref() == Some(mbid))
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, passage_id: &Uuid) -> Result<bool> {
+        Ok(self.records.lock().await.remove(passage_id).is_some())
+    }
+
+    async fn list_for_session(&self, import_session_id: &Uuid) -> Result<Vec<Uuid>> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .values()
+            .filter(|r| &r.import_session_id == import_session_id)
+            .map(|r| r.passage_id)
+            .collect())
+    }
+}
+
+/// A single write-ahead entry, enough to re-run `save_processed_passage`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalEntry {
+    file_id: Uuid,
+    import_session_id: Uuid,
+    processed: ProcessedPassage,
+}
+
+/// Write-ahead journal wrapper around another `PassageRepository`
+///
+/// Appends each save as a JSON line to `journal_path` *before* delegating to
+/// the wrapped backend, so an import session interrupted between the journal
+/// write and the underlying write completing can be replayed with
+/// [`JournaledPassageRepository::replay`] on restart.
+///
+/// Reads and deletes pass straight through to the wrapped backend - the
+/// journal only needs to durably record writes.
+pub struct JournaledPassageRepository<R: PassageRepository> {
+    inner: R,
+    journal_path: PathBuf,
+}
+
+impl<R: PassageRepository> JournaledPassageRepository<R> {
+    pub fn new(inner: R, journal_path: PathBuf) -> Self {
+        Self { inner, journal_path }
+    }
+
+    fn append_entry(&self, entry: &JournalEntry) -> Result<()> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.journal_path)?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        Ok(())
+    }
+
+    /// Replay every journaled entry into the wrapped backend
+    ///
+    /// Entries are re-applied unconditionally - `save_processed_passage` is an
+    /// insert, so replaying an already-applied entry creates a duplicate
+    /// record rather than corrupting state. Callers that need exactly-once
+    /// replay should reconcile against `list_for_session` (or truncate the
+    /// journal) once a session is known to have completed.
+    pub async fn replay(&self) -> Result<usize> {
+        if !self.journal_path.exists() {
+            return Ok(0);
+        }
+
+        let contents = std::fs::read_to_string(&self.journal_path)?;
+        let mut replayed = 0;
+
+        for line in contents.lines().filter(|l| !l.trim().is_empty()) {
+            let entry: JournalEntry = serde_json::from_str(line)?;
+            self.inner
+                .save_processed_passage(&entry.file_id, &entry.processed, &entry.import_session_id)
+                .await?;
+            replayed += 1;
+        }
+
+        Ok(replayed)
+    }
+}
+
+#[async_trait]
+impl<R: PassageRepository> PassageRepository for JournaledPassageRepository<R> {
+    async fn save_processed_passage(
+        &self,
+        file_id: &Uuid,
+        processed: &ProcessedPassage,
+        import_session_id: &Uuid,
+    ) -> Result<Uuid> {
+        self.append_entry(&JournalEntry {
+            file_id: *file_id,
+            import_session_id: *import_session_id,
+            processed: processed.clone(),
+        })?;
+
+        self.inner
+            .save_processed_passage(file_id, processed, import_session_id)
+            .await
+    }
+
+    async fn fetch_by_id(&self, passage_id: &Uuid) -> Result<Option<PassageRecord>> {
+        self.inner.fetch_by_id(passage_id).await
+    }
+
+    async fn fetch_by_mbid(&self, mbid: &Uuid) -> Result<Vec<PassageRecord>> {
+        self.inner.fetch_by_mbid(mbid).await
+    }
+
+    async fn delete(&self, passage_id: &Uuid) -> Result<bool> {
+        self.inner.delete(passage_id).await
+    }
+
+    async fn list_for_session(&self, import_session_id: &Uuid) -> Result<Vec<Uuid>> {
+        self.inner.list_for_session(import_session_id).await
+    }
+}
+
+/// Configured storage backend for `ProcessedPassage` persistence
+///
+/// Selected from the top-level `import_repository_backend` key in the TOML config; defaults
+/// to `Sqlite` when unset so existing deployments are unaffected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum RepositoryBackend {
+    #[default]
+    Sqlite,
+    InMemory,
+    /// SQLite wrapped in a write-ahead journal at the given path
+    Journaled(PathBuf),
+}
+
+impl RepositoryBackend {
+    /// Parse a backend selection from config (e.g. `"sqlite"`, `"memory"`, or
+    /// `"journaled:/path/to/journal.jsonl"`). Unrecognized values fall back to
+    /// `Sqlite`.
+    pub fn from_config_str(value: &str, default_journal_path: &PathBuf) -> Self {
+        match value {
+            "memory" | "in_memory" => Self::InMemory,
+            "journaled" => Self::Journaled(default_journal_path.clone()),
+            s if s.starts_with("journaled:") => {
+                Self::Journaled(PathBuf::from(s.trim_start_matches("journaled:")))
+            }
+            _ => Self::Sqlite,
+        }
+    }
+}
+
+/// Build the configured `PassageRepository` for engine construction
+///
+/// **[REQ-AI-088]** Centralizes backend selection so `session_orchestrator`
+/// (and any future caller) stays agnostic to which concrete storage engine is
+/// in use.
+pub fn build_passage_repository(backend: RepositoryBackend, pool: SqlitePool) -> Arc<dyn PassageRepository> {
+    match backend {
+        RepositoryBackend::Sqlite => Arc::new(SqlitePassageRepository::new(pool)),
+        RepositoryBackend::InMemory => Arc::new(InMemoryPassageRepository::new()),
+        RepositoryBackend::Journaled(journal_path) => Arc::new(JournaledPassageRepository::new(
+            SqlitePassageRepository::new(pool),
+            journal_path,
+        )),
+    }
 }
 
 /// Convert ExtractionSource to string
@@ -310,38 +652,155 @@ mod tests {
         );
     }
 
-    #[tokio::test]
-    async fn test_validation_status() {
-        let pool = sqlx::SqlitePool::connect("sqlite::memory:")
-            .await
-            .unwrap();
-        let repo = ImportRepository { pool };
-
-        let validation_pass = ValidationReport {
+    #[test]
+    fn test_derive_validation_status() {
+        let pass = ValidationReport {
             quality_score: 0.9,
             has_conflicts: false,
             warnings: vec![],
             conflicts: vec![],
         };
+        assert_eq!(derive_validation_status(&pass), "Pass");
 
-        assert_eq!(repo.validation_status(&validation_pass), "Pass");
-
-        let validation_warning = ValidationReport {
+        let warning = ValidationReport {
             quality_score: 0.9,
             has_conflicts: false,
             warnings: vec!["Missing album".to_string()],
             conflicts: vec![],
         };
+        assert_eq!(derive_validation_status(&warning), "Warning");
 
-        assert_eq!(repo.validation_status(&validation_warning), "Warning");
-
-        let validation_fail = ValidationReport {
+        let fail = ValidationReport {
             quality_score: 0.5,
             has_conflicts: true,
             warnings: vec![],
             conflicts: vec![("Conflict".to_string(), ConflictSeverity::High)],
         };
+        assert_eq!(derive_validation_status(&fail), "Fail");
+    }
+
+    fn sample_processed_passage() -> ProcessedPassage {
+        ProcessedPassage {
+            identity: ResolvedIdentity {
+                mbid: Some(Uuid::new_v4()),
+                confidence: 0.9,
+                candidates: vec![],
+                has_conflict: false,
+            },
+            metadata: FusedMetadata {
+                title: Some(MetadataField {
+                    value: "Test Song".to_string(),
+                    confidence: 0.8,
+                    source: ExtractionSource::ID3Metadata,
+                }),
+                artist: None,
+                album: None,
+                release_date: None,
+                track_number: None,
+                duration_ms: None,
+                metadata_confidence: 0.8,
+            },
+            flavor: SynthesizedFlavor {
+                flavor: MusicalFlavor { characteristics: vec![] },
+                flavor_confidence: 0.7,
+                flavor_completeness: 0.5,
+                sources_used: vec![ExtractionSource::AudioDerived],
+            },
+            boundary: PassageBoundary {
+                start_ticks: 0,
+                end_ticks: 1_000_000,
+                confidence: 0.8,
+                detection_method: BoundaryDetectionMethod::SilenceDetection,
+            },
+            validation: ValidationReport {
+                quality_score: 0.9,
+                has_conflicts: false,
+                warnings: vec![],
+                conflicts: vec![],
+            },
+            import_duration_ms: 42,
+            import_timestamp: "2026-01-01T00:00:00Z".to_string(),
+            import_version: "test".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_repository_round_trip() {
+        let repo = InMemoryPassageRepository::new();
+        let file_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let processed = sample_processed_passage();
+
+        let passage_id = repo
+            .save_processed_passage(&file_id, &processed, &session_id)
+            .await
+            .unwrap();
+
+        let fetched = repo.fetch_by_id(&passage_id).await.unwrap().unwrap();
+        assert_eq!(fetched.file_id, file_id);
+        assert_eq!(fetched.import_session_id, session_id);
+        assert_eq!(fetched.validation_status, "Pass");
+
+        let by_mbid = repo
+            .fetch_by_mbid(processed.identity.mbid.as_ref().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(by_mbid.len(), 1);
+
+        let for_session = repo.list_for_session(&session_id).await.unwrap();
+        assert_eq!(for_session, vec![passage_id]);
+
+        assert!(repo.delete(&passage_id).await.unwrap());
+        assert!(repo.fetch_by_id(&passage_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_journaled_repository_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal_path = dir.path().join("import_journal.jsonl");
+
+        let file_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let processed = sample_processed_passage();
+
+        {
+            let journaled =
+                JournaledPassageRepository::new(InMemoryPassageRepository::new(), journal_path.clone());
+            journaled
+                .save_processed_passage(&file_id, &processed, &session_id)
+                .await
+                .unwrap();
+        }
+
+        // Simulate a restart against a fresh in-memory backend, replaying the journal.
+        let recovered =
+            JournaledPassageRepository::new(InMemoryPassageRepository::new(), journal_path.clone());
+        let replayed = recovered.replay().await.unwrap();
+        assert_eq!(replayed, 1);
+
+        let for_session = recovered.list_for_session(&session_id).await.unwrap();
+        assert_eq!(for_session.len(), 1);
+    }
 
-        assert_eq!(repo.validation_status(&validation_fail), "Fail");
+    #[test]
+    fn test_repository_backend_from_config_str() {
+        let default_path = PathBuf::from("/tmp/default_journal.jsonl");
+
+        assert_eq!(
+            RepositoryBackend::from_config_str("sqlite", &default_path),
+            RepositoryBackend::Sqlite
+        );
+        assert_eq!(
+            RepositoryBackend::from_config_str("memory", &default_path),
+            RepositoryBackend::InMemory
+        );
+        assert_eq!(
+            RepositoryBackend::from_config_str("journaled:/custom/path.jsonl", &default_path),
+            RepositoryBackend::Journaled(PathBuf::from("/custom/path.jsonl"))
+        );
+        assert_eq!(
+            RepositoryBackend::from_config_str("unknown", &default_path),
+            RepositoryBackend::Sqlite
+        );
     }
 }
@@ -49,6 +49,7 @@ pub mod song_workflow_engine;  // Per-song sequential processing
 pub mod session_orchestrator;  // Session-level workflow orchestration (PLAN024)
 pub mod sse_broadcaster;  // SSE event broadcasting with throttling ✅
 pub mod db_repository;  // Database repository for ProcessedPassage ✅
+pub mod session_quality;  // Monotonic, resumable session-level quality rollup (PLAN024)
 
 // Shared types and data contracts between tiers
 pub mod types;
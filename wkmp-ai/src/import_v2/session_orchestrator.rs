@@ -11,8 +11,10 @@
 // - Error handling and cancellation
 
 use crate::db::files::{calculate_file_hash, save_file, AudioFile};
-use crate::import_v2::db_repository::ImportRepository;
+use crate::import_v2::db_repository::{build_passage_repository, PassageRepository, RepositoryBackend};
+use crate::import_v2::session_quality::SessionQualityAccumulator;
 use crate::import_v2::song_workflow_engine::SongWorkflowEngine;
+use crate::import_v2::sse_broadcaster::SseBroadcaster;
 use crate::import_v2::types::ImportEvent;
 use crate::models::{ImportSession, ImportState};
 use crate::services::FileScanner;
@@ -23,6 +25,7 @@ use lofty::prelude::AudioFile as LoftyAudioFile;
 use lofty::probe::Probe;
 use sqlx::{Pool, Sqlite};
 use std::path::Path;
+use std::sync::Arc;
 use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
 
@@ -36,33 +39,90 @@ pub struct SessionOrchestrator {
     event_tx: broadcast::Sender<ImportEvent>,
     /// Song workflow engine for per-passage processing
     engine: SongWorkflowEngine,
-    /// Database repository for ProcessedPassage persistence
-    repository: ImportRepository,
+    /// Storage backend for ProcessedPassage persistence
+    ///
+    /// **[REQ-AI-088]** Boxed as a trait object so the concrete backend
+    /// (SQLite, in-memory, journaled) is chosen once at construction and the
+    /// rest of the orchestrator stays agnostic to which one is active.
+    repository: Arc<dyn PassageRepository>,
+    /// Monotonic session-wide quality rollup, immune to transient re-validation noise
+    quality: SessionQualityAccumulator,
+    /// Dedicated broadcaster for session-quality-rollup events
+    ///
+    /// Separate from `engine`'s own `SseBroadcaster` since quality updates are
+    /// session-level, not per-passage, and are already gated by `quality`
+    /// recording a real change rather than by time-based throttling.
+    quality_broadcaster: SseBroadcaster,
 }
 
 impl SessionOrchestrator {
-    /// Create new session orchestrator
+    /// Create new session orchestrator, resolving the repository backend from `TomlConfig`
+    ///
+    /// **[REQ-AI-088]** Reads `toml_config.import_repository_backend` (one of
+    /// `"sqlite"`, `"memory"`, or `"journaled:<path>"`; missing or unrecognized
+    /// values fall back to `RepositoryBackend::Sqlite`). Use
+    /// `with_repository_backend` directly to bypass config resolution, e.g.
+    /// in tests.
     ///
     /// # Arguments
     /// * `db` - Database connection pool
     /// * `event_tx` - Broadcast sender for SSE events
     /// * `throttle_interval_ms` - SSE throttle interval (default: 1000ms)
+    /// * `toml_config` - TOML configuration (source of the backend selection)
     pub fn new(
         db: Pool<Sqlite>,
         event_tx: broadcast::Sender<ImportEvent>,
         throttle_interval_ms: u64,
+        toml_config: &wkmp_common::config::TomlConfig,
+    ) -> Self {
+        let default_journal_path = std::path::PathBuf::from("import_passages.journal.jsonl");
+        let backend = toml_config
+            .import_repository_backend
+            .as_deref()
+            .map(|s| RepositoryBackend::from_config_str(s, &default_journal_path))
+            .unwrap_or_default();
+        Self::with_repository_backend(db, event_tx, throttle_interval_ms, backend)
+    }
+
+    /// Create new session orchestrator with an explicitly configured repository backend
+    ///
+    /// **[REQ-AI-088]** Bypasses `TomlConfig` resolution entirely; tests can
+    /// pass `RepositoryBackend::InMemory` to avoid touching disk.
+    ///
+    /// # Arguments
+    /// * `db` - Database connection pool
+    /// * `event_tx` - Broadcast sender for SSE events
+    /// * `throttle_interval_ms` - SSE throttle interval (default: 1000ms)
+    /// * `backend` - Storage backend selection for passage persistence
+    pub fn with_repository_backend(
+        db: Pool<Sqlite>,
+        event_tx: broadcast::Sender<ImportEvent>,
+        throttle_interval_ms: u64,
+        backend: RepositoryBackend,
     ) -> Self {
         let engine = SongWorkflowEngine::with_sse(event_tx.clone(), throttle_interval_ms);
-        let repository = ImportRepository::new(db.clone());
+        let repository = build_passage_repository(backend, db.clone());
+        let quality_broadcaster = SseBroadcaster::new(event_tx.clone(), throttle_interval_ms);
 
         Self {
             db,
             event_tx,
             engine,
             repository,
+            quality: SessionQualityAccumulator::new(),
+            quality_broadcaster,
         }
     }
 
+    /// Current session-wide quality rollup: count-by-status and a weighted mean
+    ///
+    /// **[PLAN024]** Safe to poll at any point during `execute_import` - the
+    /// rollup only ever improves or reflects a genuine passage content
+    /// change, never a transient re-validation regression.
+    pub fn session_quality_summary(&self) -> crate::import_v2::session_quality::SessionQualitySummary {
+        self.quality.session_quality_summary()
+    }
+
     /// Initialize API clients from configuration
     ///
     /// Must be called after construction before executing workflow
@@ -538,6 +598,23 @@ impl SessionOrchestrator {
                             // Database errors are non-fatal - workflow continues
                         }
                     }
+
+                    // Roll the passage's result into the session-wide quality summary.
+                    // **[PLAN024]** Keyed by (file_id, boundary) rather than the random
+                    // passage_id above, so re-validating this passage later in the same
+                    // session updates the same rollup entry instead of double-counting it.
+                    let passage_key = (file_id, passage_boundary.start_ticks, passage_boundary.end_ticks);
+                    let content_hash = SessionQualityAccumulator::content_hash(&processed.metadata);
+                    if self.quality.record(passage_key, &processed.validation, content_hash) {
+                        let summary = self.quality.session_quality_summary();
+                        self.quality_broadcaster.emit_immediate(ImportEvent::SessionQualityUpdated {
+                            session_id: session.session_id,
+                            pass_count: summary.pass_count,
+                            warning_count: summary.warning_count,
+                            fail_count: summary.fail_count,
+                            weighted_mean_quality: summary.weighted_mean_quality,
+                        });
+                    }
                 } else {
                     failures += 1;
                     let error_msg = result.error.as_deref().unwrap_or("Unknown error");
@@ -0,0 +1,255 @@
+// PLAN024: Monotonic, resumable session-level quality aggregation
+//
+// Concept: Roll individual passage validation results up into a session-wide
+// quality summary that is immune to transient re-validation noise.
+//
+// A long import session may re-run validation on an already-processed
+// passage (e.g. after a MusicBrainz re-query is retried). A transient
+// network failure during that re-run can make the passage's quality score
+// temporarily collapse and then recover. Without safeguarding against this,
+// the session-level aggregate would flap down and back up even though
+// nothing about the passage actually changed.
+//
+// This accumulator keeps the best-observed (score, status) per passage plus
+// a content hash of the fused metadata that produced it, and only moves the
+// rollup when a later result is a strict improvement or the content hash
+// shows the passage's inputs genuinely changed.
+
+use crate::import_v2::types::{FusedMetadata, ValidationReport};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use uuid::Uuid;
+
+/// Validation status bucket counted in the session rollup
+///
+/// Mirrors the `validation_status` strings written by `db_repository`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QualityStatus {
+    Pass,
+    Warning,
+    Fail,
+}
+
+impl QualityStatus {
+    fn from_validation(validation: &ValidationReport) -> Self {
+        if validation.has_conflicts {
+            Self::Fail
+        } else if !validation.warnings.is_empty() {
+            Self::Warning
+        } else if validation.quality_score >= 0.8 {
+            Self::Pass
+        } else {
+            Self::Warning
+        }
+    }
+}
+
+/// Best-observed result recorded for a single passage
+#[derive(Debug, Clone)]
+struct PassageQuality {
+    score: f64,
+    status: QualityStatus,
+    content_hash: u64,
+}
+
+/// Count-by-status plus a weighted mean, safe to broadcast to SSE clients
+///
+/// "Weighted" here refers to each passage's `quality_score` already being a
+/// weight-by-confidence composite (see `tier3::completeness_scorer`) - the
+/// rollup is simply the mean of those composites across recorded passages.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SessionQualitySummary {
+    pub pass_count: usize,
+    pub warning_count: usize,
+    pub fail_count: usize,
+    pub weighted_mean_quality: f64,
+}
+
+/// Stable passage identity within a session: (file_id, start_ticks, end_ticks)
+///
+/// Deliberately independent of the random `passage_id` that `db_repository`
+/// assigns on every `save_processed_passage` call, so the same passage keeps
+/// the same key across re-validation runs within a session.
+pub type PassageKey = (Uuid, i64, i64);
+
+/// Accumulates per-passage quality results into a monotonic session rollup
+///
+/// **Invariant:** the session-wide `weighted_mean_quality` never decreases
+/// because an already-counted passage was re-validated with a worse, stale
+/// result - only a strictly better score, or a changed content hash
+/// (indicating the passage's fused metadata genuinely changed), can move it.
+#[derive(Default)]
+pub struct SessionQualityAccumulator {
+    passages: HashMap<PassageKey, PassageQuality>,
+}
+
+impl SessionQualityAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash the fused metadata that fed into a validation run
+    ///
+    /// Used to distinguish "the same passage, re-validated, with a transient
+    /// blip" (hash unchanged) from "the passage's inputs genuinely changed"
+    /// (hash changed).
+    pub fn content_hash(metadata: &FusedMetadata) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        metadata.title.as_ref().map(|f| &f.value).hash(&mut hasher);
+        metadata.artist.as_ref().map(|f| &f.value).hash(&mut hasher);
+        metadata.album.as_ref().map(|f| &f.value).hash(&mut hasher);
+        metadata.release_date.as_ref().map(|f| &f.value).hash(&mut hasher);
+        metadata.track_number.as_ref().map(|f| f.value).hash(&mut hasher);
+        metadata.duration_ms.as_ref().map(|f| f.value).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Record a passage's validation result
+    ///
+    /// Returns `true` if the session rollup actually changed (a new passage,
+    /// a strict score improvement, or a genuine content change), `false` if
+    /// the result was discarded as transient re-validation noise.
+    pub fn record(&mut self, passage_key: PassageKey, validation: &ValidationReport, content_hash: u64) -> bool {
+        let status = QualityStatus::from_validation(validation);
+        let score = validation.quality_score;
+
+        match self.passages.get_mut(&passage_key) {
+            Some(existing) => {
+                let content_changed = existing.content_hash != content_hash;
+                let improved = score > existing.score;
+                if !content_changed && !improved {
+                    return false;
+                }
+                existing.score = score;
+                existing.status = status;
+                existing.content_hash = content_hash;
+                true
+            }
+            None => {
+                self.passages.insert(passage_key, PassageQuality { score, status, content_hash });
+                true
+            }
+        }
+    }
+
+    /// Count-by-status and weighted mean across all recorded passages
+    pub fn session_quality_summary(&self) -> SessionQualitySummary {
+        let mut pass_count = 0;
+        let mut warning_count = 0;
+        let mut fail_count = 0;
+        let mut score_sum = 0.0;
+
+        for quality in self.passages.values() {
+            match quality.status {
+                QualityStatus::Pass => pass_count += 1,
+                QualityStatus::Warning => warning_count += 1,
+                QualityStatus::Fail => fail_count += 1,
+            }
+            score_sum += quality.score;
+        }
+
+        let total = self.passages.len();
+        let weighted_mean_quality = if total > 0 { score_sum / total as f64 } else { 0.0 };
+
+        SessionQualitySummary {
+            pass_count,
+            warning_count,
+            fail_count,
+            weighted_mean_quality,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import_v2::types::MetadataField;
+    use crate::import_v2::types::ExtractionSource;
+
+    fn validation(quality_score: f64) -> ValidationReport {
+        ValidationReport {
+            quality_score,
+            has_conflicts: false,
+            warnings: vec![],
+            conflicts: vec![],
+        }
+    }
+
+    #[test]
+    fn test_first_result_always_recorded() {
+        let mut acc = SessionQualityAccumulator::new();
+        let passage: PassageKey = (Uuid::new_v4(), 0, 1_000_000);
+
+        assert!(acc.record(passage, &validation(0.9), 42));
+        let summary = acc.session_quality_summary();
+        assert_eq!(summary.pass_count, 1);
+        assert_eq!(summary.weighted_mean_quality, 0.9);
+    }
+
+    #[test]
+    fn test_transient_regression_discarded() {
+        let mut acc = SessionQualityAccumulator::new();
+        let passage: PassageKey = (Uuid::new_v4(), 0, 1_000_000);
+
+        assert!(acc.record(passage, &validation(0.9), 42));
+        // Same content hash, worse score: a transient re-validation blip.
+        assert!(!acc.record(passage, &validation(0.3), 42));
+
+        let summary = acc.session_quality_summary();
+        assert_eq!(summary.weighted_mean_quality, 0.9, "regression must not move the rollup");
+    }
+
+    #[test]
+    fn test_strict_improvement_recorded() {
+        let mut acc = SessionQualityAccumulator::new();
+        let passage: PassageKey = (Uuid::new_v4(), 0, 1_000_000);
+
+        acc.record(passage, &validation(0.6), 42);
+        assert!(acc.record(passage, &validation(0.95), 42));
+
+        let summary = acc.session_quality_summary();
+        assert_eq!(summary.weighted_mean_quality, 0.95);
+    }
+
+    #[test]
+    fn test_content_change_allows_regression() {
+        let mut acc = SessionQualityAccumulator::new();
+        let passage: PassageKey = (Uuid::new_v4(), 0, 1_000_000);
+
+        acc.record(passage, &validation(0.9), 42);
+        // Different content hash: inputs genuinely changed, so even a worse
+        // score is accepted as the new recorded state.
+        assert!(acc.record(passage, &validation(0.5), 99));
+
+        let summary = acc.session_quality_summary();
+        assert_eq!(summary.weighted_mean_quality, 0.5);
+    }
+
+    #[test]
+    fn test_content_hash_sensitive_to_title() {
+        let metadata_a = FusedMetadata {
+            title: Some(MetadataField {
+                value: "Song A".to_string(),
+                confidence: 0.8,
+                source: ExtractionSource::ID3Metadata,
+            }),
+            artist: None,
+            album: None,
+            release_date: None,
+            track_number: None,
+            duration_ms: None,
+            metadata_confidence: 0.8,
+        };
+        let mut metadata_b = metadata_a.clone();
+        metadata_b.title = Some(MetadataField {
+            value: "Song B".to_string(),
+            confidence: 0.8,
+            source: ExtractionSource::ID3Metadata,
+        });
+
+        assert_ne!(
+            SessionQualityAccumulator::content_hash(&metadata_a),
+            SessionQualityAccumulator::content_hash(&metadata_b)
+        );
+    }
+}
@@ -330,6 +330,7 @@ mod tests {
             logging: Default::default(),
             static_assets: None,
             acoustid_api_key: None,
+            import_repository_backend: None,
             musicbrainz_token: None,
         };
 
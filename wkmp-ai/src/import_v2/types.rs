@@ -60,7 +60,7 @@ impl ExtractionSource {
 // ============================================================================
 
 /// MusicBrainz Recording ID candidate with confidence
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MBIDCandidate {
     pub mbid: Uuid,  // MusicBrainz Recording ID
     pub confidence: f64,  // Posterior probability after Bayesian update
@@ -68,7 +68,7 @@ pub struct MBIDCandidate {
 }
 
 /// Resolved identity (output of IdentityResolver)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResolvedIdentity {
     pub mbid: Option<Uuid>,  // None if no confident match
     pub confidence: f64,     // Final confidence after fusion
@@ -81,7 +81,7 @@ pub struct ResolvedIdentity {
 // ============================================================================
 
 /// Metadata field with source provenance
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetadataField<T> {
     pub value: T,
     pub confidence: f64,
@@ -100,7 +100,7 @@ pub struct MetadataBundle {
 }
 
 /// Fused metadata (output of MetadataFuser)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FusedMetadata {
     pub title: Option<MetadataField<String>>,
     pub artist: Option<MetadataField<String>>,
@@ -183,7 +183,7 @@ pub struct FlavorExtraction {
 }
 
 /// Synthesized musical flavor (output of FlavorSynthesizer)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SynthesizedFlavor {
     pub flavor: MusicalFlavor,
     pub flavor_confidence: f64,  // Overall flavor quality
@@ -199,7 +199,7 @@ pub struct SynthesizedFlavor {
 ///
 /// **[SRC-DB-010]** Time values are stored as ticks (i64) for sample-accurate precision.
 /// Tick rate: 28,224,000 Hz (1 tick ≈ 35.4 nanoseconds)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct PassageBoundary {
     pub start_ticks: i64,  // Passage start (ticks from file start)
     pub end_ticks: i64,    // Passage end (ticks from file start)
@@ -207,7 +207,7 @@ pub struct PassageBoundary {
     pub detection_method: BoundaryDetectionMethod,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BoundaryDetectionMethod {
     SilenceDetection,
     BeatTracking,  // Future
@@ -226,7 +226,7 @@ pub enum ValidationResult {
     Conflict { message: String, severity: ConflictSeverity },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConflictSeverity {
     Low,     // Minor inconsistency (e.g., capitalization difference)
     Medium,  // Moderate inconsistency (e.g., different release year)
@@ -234,7 +234,7 @@ pub enum ConflictSeverity {
 }
 
 /// Complete validation report
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationReport {
     pub quality_score: f64,  // [0.0, 1.0] overall quality
     pub has_conflicts: bool,
@@ -247,7 +247,10 @@ pub struct ValidationReport {
 // ============================================================================
 
 /// Complete data for a single passage after all processing
-#[derive(Debug, Clone)]
+///
+/// Serializable so it can be written to the `db_repository` write-ahead journal
+/// and replayed after a crash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessedPassage {
     // Identity
     pub identity: ResolvedIdentity,
@@ -377,6 +380,18 @@ pub enum ImportEvent {
         failures: usize,
         total_duration_ms: u64,
     },
+
+    /// Session-wide quality rollup changed (PLAN024)
+    ///
+    /// Emitted only when `session_quality::SessionQualityAccumulator` records
+    /// a real change - never on transient re-validation noise.
+    SessionQualityUpdated {
+        session_id: uuid::Uuid,
+        pass_count: usize,
+        warning_count: usize,
+        fail_count: usize,
+        weighted_mean_quality: f64,
+    },
 }
 
 // ============================================================================
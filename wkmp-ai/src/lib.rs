@@ -9,6 +9,7 @@ pub mod error;
 pub mod extractors;  // PLAN024 TASK-004: Tier 1 source extractors
 pub mod ffi;  // PLAN024: FFI bindings (Chromaprint)
 pub mod fusion;  // PLAN024: Tier 2 fusion layer
+pub mod import_v2;  // PLAN024: backend-pluggable import session orchestration
 pub mod models;
 pub mod services;
 pub mod types;  // PLAN024 TASK-004: Base traits and types
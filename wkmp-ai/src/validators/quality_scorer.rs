@@ -334,20 +334,29 @@ impl QualityScorer {
         let mut usability_components = Vec::new();
 
         // Component 1: Can identify recording? (weight: 0.4)
-        let can_identify = if passage.identity.recording_mbid.is_some() {
-            1.0
-        } else {
-            recommendations.push("Missing recording MBID (cannot track playback history)".to_string());
-            0.0
+        //
+        // Weighted by identification confidence rather than flat presence, so
+        // a low-confidence MBID match doesn't score the same as a certain one.
+        let can_identify = match passage.identity.recording_mbid {
+            Some(_) => passage.identity.posterior_probability.max(passage.identity.confidence),
+            None => {
+                recommendations.push("Missing recording MBID (cannot track playback history)".to_string());
+                0.0
+            }
         };
         usability_components.push(can_identify * 0.4);
 
         // Component 2: Can display to user? (weight: 0.4)
-        let can_display = if passage.metadata.title.is_some() && passage.metadata.artist.is_some() {
-            1.0
-        } else {
-            recommendations.push("Missing title or artist (cannot display properly)".to_string());
-            0.0
+        //
+        // Weighted by the title/artist fields' own confidence rather than
+        // flat presence, so a barely-confident title/artist match doesn't
+        // score the same as a certain one.
+        let can_display = match (&passage.metadata.title, &passage.metadata.artist) {
+            (Some(title), Some(artist)) => (title.confidence + artist.confidence) / 2.0,
+            _ => {
+                recommendations.push("Missing title or artist (cannot display properly)".to_string());
+                0.0
+            }
         };
         usability_components.push(can_display * 0.4);
 
@@ -626,6 +635,39 @@ mod tests {
         assert!(validation.issues.len() >= 2);
     }
 
+    #[tokio::test]
+    async fn test_score_low_confidence_identity_reduces_usability() {
+        // A barely-confident MBID/title/artist match should score usability
+        // lower than a certain one, instead of the flat 1.0 a presence-only
+        // check would give both.
+        let scorer = QualityScorer::new();
+
+        let mut confident = create_high_quality_passage();
+        confident.identity.conflicts = vec![]; // isolate usability from consistency
+
+        let mut uncertain = confident.clone();
+        uncertain.identity.confidence = 0.5;
+        uncertain.identity.posterior_probability = 0.5;
+        uncertain.metadata.title = Some(ConfidenceValue::new(
+            "Test Song".to_string(),
+            0.5,
+            "ID3".to_string(),
+        ));
+        uncertain.metadata.artist = Some(ConfidenceValue::new(
+            "Test Artist".to_string(),
+            0.5,
+            "ID3".to_string(),
+        ));
+
+        let confident_result = scorer.validate(&confident).await.unwrap();
+        let uncertain_result = scorer.validate(&uncertain).await.unwrap();
+
+        assert!(
+            uncertain_result.score < confident_result.score,
+            "low-confidence identity/metadata should score lower, not the same as a confident match"
+        );
+    }
+
     #[tokio::test]
     async fn test_report_structure() {
         let scorer = QualityScorer::new();
@@ -41,6 +41,7 @@ async fn test_database_overrides_env_and_toml() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("toml-key".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -72,6 +73,7 @@ async fn test_env_fallback_when_database_empty() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("toml-key".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -104,6 +106,7 @@ async fn test_toml_fallback_when_db_and_env_empty() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("toml-key".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -131,6 +134,7 @@ async fn test_error_when_no_key_found() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: None,
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -167,6 +171,7 @@ async fn test_database_ignores_env() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: None,
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -200,6 +205,7 @@ async fn test_database_ignores_toml() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("toml-key".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -228,6 +234,7 @@ async fn test_env_ignores_toml() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("toml-key".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -263,6 +270,7 @@ async fn test_multiple_sources_warning() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("toml-key".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -336,6 +344,7 @@ async fn test_sync_settings_preserves_existing_fields() {
         logging: wkmp_common::config::LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: None,
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
     wkmp_common::config::write_toml_config(&initial_config, &toml_path).unwrap();
@@ -16,7 +16,7 @@ use chrono;
 use sqlx::SqlitePool;
 use std::collections::HashMap;
 use uuid::Uuid;
-use wkmp_ai::import_v2::db_repository::ImportRepository;
+use wkmp_ai::import_v2::db_repository::{PassageRepository, SqlitePassageRepository};
 use wkmp_ai::import_v2::types::*;
 use wkmp_common::db::migrations::run_migrations;
 
@@ -206,7 +206,7 @@ fn create_test_processed_passage() -> ProcessedPassage {
 #[tokio::test]
 async fn test_flavor_source_provenance_storage() {
     let pool = setup_test_db().await;
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     let file_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
@@ -256,7 +256,7 @@ async fn test_flavor_source_provenance_storage() {
 #[tokio::test]
 async fn test_metadata_source_provenance_storage() {
     let pool = setup_test_db().await;
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     let file_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
@@ -309,7 +309,7 @@ async fn test_metadata_source_provenance_storage() {
 #[tokio::test]
 async fn test_identity_resolution_tracking() {
     let pool = setup_test_db().await;
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     let file_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
@@ -361,7 +361,7 @@ async fn test_identity_resolution_tracking() {
 #[tokio::test]
 async fn test_quality_scores_storage() {
     let pool = setup_test_db().await;
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     let file_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
@@ -406,7 +406,7 @@ async fn test_quality_scores_storage() {
 #[tokio::test]
 async fn test_validation_flags_storage() {
     let pool = setup_test_db().await;
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     let file_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
@@ -454,7 +454,7 @@ async fn test_validation_flags_storage() {
 #[tokio::test]
 async fn test_import_metadata_storage() {
     let pool = setup_test_db().await;
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     let file_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
@@ -501,7 +501,7 @@ async fn test_import_metadata_storage() {
 #[tokio::test]
 async fn test_import_provenance_log_queries() {
     let pool = setup_test_db().await;
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     let file_id = Uuid::new_v4();
     let session_id = Uuid::new_v4();
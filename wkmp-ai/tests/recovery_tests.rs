@@ -115,6 +115,7 @@ async fn test_database_deletion_no_toml_fails() {
         logging: wkmp_common::config::LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: None,
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -121,7 +121,7 @@ fn create_test_audio_file() -> (TempDir, PathBuf) {
 #[serial]
 async fn test_file_saved_before_passage_foreign_key() {
     use wkmp_ai::db::files::{calculate_file_hash, save_file, AudioFile};
-    use wkmp_ai::import_v2::db_repository::ImportRepository;
+    use wkmp_ai::import_v2::db_repository::{PassageRepository, SqlitePassageRepository};
     use chrono::Utc;
 
     let pool = setup_test_db().await;
@@ -152,7 +152,7 @@ async fn test_file_saved_before_passage_foreign_key() {
 
     // Now save passage (should succeed because file exists)
     let processed = create_minimal_processed_passage();
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     let result = repo
         .save_processed_passage(&file_id, &processed, &session_id)
@@ -182,7 +182,7 @@ async fn test_file_saved_before_passage_foreign_key() {
 #[tokio::test]
 #[serial]
 async fn test_foreign_key_constraint_enforced() {
-    use wkmp_ai::import_v2::db_repository::ImportRepository;
+    use wkmp_ai::import_v2::db_repository::{PassageRepository, SqlitePassageRepository};
 
     let pool = setup_test_db().await;
 
@@ -193,7 +193,7 @@ async fn test_foreign_key_constraint_enforced() {
     // This simulates the bug that was fixed
 
     let processed = create_minimal_processed_passage();
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     // Attempt to save passage without file record
     let result = repo
@@ -223,7 +223,7 @@ async fn test_foreign_key_constraint_enforced() {
 #[serial]
 async fn test_workflow_order_file_before_passage() {
     use wkmp_ai::db::files::{calculate_file_hash, save_file, AudioFile};
-    use wkmp_ai::import_v2::db_repository::ImportRepository;
+    use wkmp_ai::import_v2::db_repository::{PassageRepository, SqlitePassageRepository};
     use chrono::Utc;
 
     let pool = setup_test_db().await;
@@ -258,7 +258,7 @@ async fn test_workflow_order_file_before_passage() {
 
     // **STEP 2**: Passage processing and save (Phase 4 in SessionOrchestrator)
     let processed = create_minimal_processed_passage();
-    let repo = ImportRepository::new(pool.clone());
+    let repo = SqlitePassageRepository::new(pool.clone());
 
     repo.save_processed_passage(&file_id, &processed, &session_id)
         .await
@@ -201,6 +201,10 @@ pub struct BuildInfoResponse {
 #[derive(Debug, Deserialize)]
 pub struct BrowseFilesRequest {
     path: Option<String>,
+    /// Opt-in: when `true`, populate audio tag metadata (title/artist/album/duration)
+    /// on each audio file entry. Off by default so plain directory listings stay fast.
+    #[serde(default)]
+    metadata: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -216,6 +220,48 @@ pub struct FileEntry {
     path: String,
     is_directory: bool,
     is_audio_file: bool,
+    /// Track title read from audio tags. `None` when metadata wasn't requested,
+    /// the file has no tags, or tag parsing failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    artist: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    album: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_secs: Option<f64>,
+}
+
+/// Read title/artist/album/duration from an audio file's tags.
+///
+/// [ARCH-FB-010] Best-effort: any missing tag or parse failure yields `None` fields
+/// rather than failing the enclosing `browse_files` request.
+fn read_audio_tags(path: &std::path::Path) -> (Option<String>, Option<String>, Option<String>, Option<f64>) {
+    use lofty::file::{AudioFile, TaggedFileExt};
+    use lofty::probe::Probe;
+    use lofty::tag::Accessor;
+
+    let tagged_file = match Probe::open(path).and_then(|p| p.read()) {
+        Ok(f) => f,
+        Err(e) => {
+            warn!(file = ?path, error = %e, "Failed to read audio tags for browse metadata");
+            return (None, None, None, None);
+        }
+    };
+
+    let duration_secs = Some(tagged_file.properties().duration().as_secs_f64());
+
+    let tag = tagged_file.primary_tag().or_else(|| tagged_file.first_tag());
+    let Some(tag) = tag else {
+        return (None, None, None, duration_secs);
+    };
+
+    (
+        tag.title().map(|s| s.to_string()),
+        tag.artist().map(|s| s.to_string()),
+        tag.album().map(|s| s.to_string()),
+        duration_secs,
+    )
 }
 
 // ============================================================================
@@ -936,6 +982,11 @@ pub async fn reorder_queue_entry(
 /// **[ARCH-FB-010]** File browser for developer UI
 /// Allows browsing directories and selecting audio files to enqueue.
 /// Security: Only allows browsing within configured root folder.
+///
+/// Pass `?metadata=true` to populate `title`/`artist`/`album`/`duration_secs` on
+/// audio file entries by reading their tags. Off by default, since tag reads are
+/// much slower than a plain directory listing; fields are `None` when a file has
+/// no tags or fails to parse.
 pub async fn browse_files(
     State(ctx): State<AppContext>,
     axum::extract::Query(req): axum::extract::Query<BrowseFilesRequest>,
@@ -1103,11 +1154,29 @@ pub async fn browse_files(
 
             // Only include directories and audio files
             if is_directory || is_audio_file {
+                let (title, artist, album, duration_secs) = if req.metadata && is_audio_file {
+                    // Re-validate against canonical_root: entries come from read_dir on an
+                    // already-validated directory, but tag parsing follows symlinks/hardlinks
+                    // so we don't trust `path` blindly before opening it.
+                    match fs::canonicalize(&path) {
+                        Ok(canonical_entry) if canonical_entry.starts_with(&canonical_root) => {
+                            read_audio_tags(&canonical_entry)
+                        }
+                        _ => (None, None, None, None),
+                    }
+                } else {
+                    (None, None, None, None)
+                };
+
                 file_entries.push(FileEntry {
                     name,
                     path: clean_path_for_display(&path),
                     is_directory,
                     is_audio_file,
+                    title,
+                    artist,
+                    album,
+                    duration_secs,
                 });
             }
         }
@@ -1166,11 +1235,15 @@ pub async fn get_build_info() -> Json<BuildInfoResponse> {
 /// - `rate_ms: 100` - Fast updates (10Hz) for visualizing rapid buffer filling
 /// - `rate_ms: 1000` - Normal updates (1Hz) for typical monitoring
 /// - `rate_ms: 0` - Manual mode (no automatic updates, only on explicit trigger)
+///
+/// **[SPEC020-MONITOR-140]** When `auto: true`, `rate_ms` is ignored and the
+/// emission interval instead adapts between the configured min/max bounds
+/// based on how fast the now-playing chain's buffer fill level is trending.
 pub async fn set_buffer_monitor_rate(
     State(ctx): State<AppContext>,
     Json(payload): Json<SetBufferMonitorRateRequest>,
 ) -> StatusCode {
-    ctx.engine.set_buffer_monitor_rate(payload.rate_ms).await;
+    ctx.engine.set_buffer_monitor_rate(payload.rate_ms, payload.auto).await;
     StatusCode::OK
 }
 
@@ -1191,6 +1264,70 @@ pub async fn trigger_buffer_monitor_update(
 pub struct SetBufferMonitorRateRequest {
     /// Update interval in milliseconds (100, 1000, or 0 for manual)
     pub rate_ms: u64,
+    /// **[SPEC020-MONITOR-140]** Trend-aware adaptive rate; overrides `rate_ms` when true
+    #[serde(default)]
+    pub auto: bool,
+}
+
+// ============================================================================
+// Output Spectrum Visualization
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct SpectrumResponse {
+    /// Downsampled magnitude bars (log-spaced bucket averages)
+    bars: Vec<f32>,
+    db_scale: bool,
+}
+
+/// GET /playback/spectrum - Get current output spectrum magnitude bars
+///
+/// **[SPEC020-SPECTRUM-010]** On-demand snapshot of the same FFT the
+/// `SpectrumUpdate` SSE event carries; mirrors how `/playback/buffer_chains`
+/// complements the `BufferChainStatus` SSE stream on `/events`.
+///
+/// `bars` is empty when fewer than `spectrum_fft_size` output samples have
+/// been produced yet (e.g. right after playback starts).
+pub async fn get_spectrum(
+    State(ctx): State<AppContext>,
+) -> Json<SpectrumResponse> {
+    const DB_SCALE: bool = true;
+    let bars = ctx.engine.get_spectrum(DB_SCALE).await.unwrap_or_default();
+    Json(SpectrumResponse { bars, db_scale: DB_SCALE })
+}
+
+/// POST /playback/spectrum/rate - Set spectrum visualizer SSE update rate
+///
+/// **[SPEC020-SPECTRUM-010]** Client-controlled SSE emission rate
+///
+/// Sets the rate at which SpectrumUpdate SSE events are emitted on `/events`:
+/// - `rate_ms: 100` - Fast updates (10Hz) for a responsive visualizer
+/// - `rate_ms: 1000` - Normal updates (1Hz)
+/// - `rate_ms: 0` - Manual mode (no automatic updates, only on explicit trigger)
+pub async fn set_spectrum_rate(
+    State(ctx): State<AppContext>,
+    Json(payload): Json<SetSpectrumRateRequest>,
+) -> StatusCode {
+    ctx.engine.set_spectrum_rate(payload.rate_ms).await;
+    StatusCode::OK
+}
+
+/// POST /playback/spectrum/update - Trigger immediate spectrum update
+///
+/// **[SPEC020-SPECTRUM-010]** Manual update trigger
+///
+/// Forces one immediate SpectrumUpdate SSE emission, regardless of current mode.
+pub async fn trigger_spectrum_update(
+    State(ctx): State<AppContext>,
+) -> StatusCode {
+    ctx.engine.trigger_spectrum_update();
+    StatusCode::OK
+}
+
+#[derive(serde::Deserialize)]
+pub struct SetSpectrumRateRequest {
+    /// Update interval in milliseconds (100, 1000, or 0 for manual)
+    pub rate_ms: u64,
 }
 
 // ============================================================================
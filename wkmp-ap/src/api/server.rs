@@ -110,6 +110,9 @@ pub async fn run(
         .route("/playback/buffer_chains", get(super::handlers::get_buffer_chains))
         .route("/playback/buffer_monitor/rate", post(super::handlers::set_buffer_monitor_rate))
         .route("/playback/buffer_monitor/update", post(super::handlers::trigger_buffer_monitor_update))
+        .route("/playback/spectrum", get(super::handlers::get_spectrum))
+        .route("/playback/spectrum/rate", post(super::handlers::set_spectrum_rate))
+        .route("/playback/spectrum/update", post(super::handlers::trigger_spectrum_update))
         .route("/playback/callback_stats", get(super::handlers::get_callback_stats))
 
         // Pipeline diagnostics
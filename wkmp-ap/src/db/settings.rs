@@ -493,6 +493,64 @@ pub async fn load_playout_ringbuffer_headroom(db: &Pool<Sqlite>) -> Result<usize
     load_clamped_setting(db, "playout_ringbuffer_headroom", 1_000, 44_100, 4_410).await
 }
 
+/// Load spectrum analyzer FFT window size from database
+///
+/// **[SPEC020-SPECTRUM-010]** Output spectrum visualizer FFT size
+///
+/// # Returns
+/// FFT window size in mono samples (default: 2048). Should be a power of two.
+/// Clamped to valid range: 256-8192 samples.
+pub async fn load_spectrum_fft_size(db: &Pool<Sqlite>) -> Result<usize> {
+    load_clamped_setting(db, "spectrum_fft_size", 256usize, 8192usize, 2048usize).await
+}
+
+/// Buffer monitor "auto" mode thresholds
+///
+/// **[SPEC020-MONITOR-140]** Trend-aware adaptive emission rate
+#[derive(Debug, Clone, Copy)]
+pub struct BufferMonitorAutoConfig {
+    /// Absolute fill-level slope (percentage points/sec) at or above which the
+    /// emitter uses `min_interval_ms`
+    pub slope_threshold_pct_per_sec: f64,
+    /// Shortest emission interval (fast updates during rapid fill/drain)
+    pub min_interval_ms: u64,
+    /// Longest emission interval (steady-state, no firehose)
+    pub max_interval_ms: u64,
+}
+
+/// Load buffer monitor "auto" mode thresholds from database
+///
+/// **[SPEC020-MONITOR-140]** Trend-aware adaptive emission rate
+///
+/// # Returns
+/// `slope_threshold_pct_per_sec` (default: 5.0, clamped 0.1-100.0),
+/// `min_interval_ms` (default: 100, clamped 50-1000),
+/// `max_interval_ms` (default: 1000, clamped 100-5000)
+pub async fn load_buffer_monitor_auto_config(db: &Pool<Sqlite>) -> Result<BufferMonitorAutoConfig> {
+    let slope_threshold_pct_per_sec = match get_setting::<f64>(db, "buffer_monitor_auto_slope_threshold_pct_per_sec").await? {
+        Some(v) => v.clamp(0.1, 100.0),
+        None => 5.0,
+    };
+    let min_interval_ms = load_clamped_setting(db, "buffer_monitor_auto_min_interval_ms", 50u64, 1000u64, 100u64).await?;
+    let max_interval_ms = load_clamped_setting(db, "buffer_monitor_auto_max_interval_ms", 100u64, 5000u64, 1000u64).await?;
+
+    Ok(BufferMonitorAutoConfig {
+        slope_threshold_pct_per_sec,
+        min_interval_ms,
+        max_interval_ms,
+    })
+}
+
+/// Load spectrum visualizer output bar count from database
+///
+/// **[SPEC020-SPECTRUM-010]** Number of log-spaced magnitude bars emitted per update
+///
+/// # Returns
+/// Bar count (default: 32). Clamped to valid range: 8-128 bars.
+pub async fn load_spectrum_bar_count(db: &Pool<Sqlite>) -> Result<usize> {
+    load_clamped_setting(db, "spectrum_bar_count", 8usize, 128usize, 32usize).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,7 +64,8 @@ impl PlaybackEngine {
         // Assign chains to each entry
         for (idx, queue_entry_id) in queue_entry_ids.iter().enumerate() {
             debug!("🔍 assign_chains_to_loaded_queue: Processing entry {}/{}: {}", idx + 1, count, queue_entry_id);
-            self.assign_chain(*queue_entry_id).await;
+            let priority = self.decode_priority_for_entry(*queue_entry_id).await;
+            self.assign_chain(*queue_entry_id, priority).await;
             debug!("🔍 assign_chains_to_loaded_queue: Completed entry {}/{}", idx + 1, count);
         }
 
@@ -126,42 +127,112 @@ impl PlaybackEngine {
         debug!("🔍 assign_chains_to_loaded_queue: DONE");
     }
 
+    /// Determine the decode priority for a queue entry from its current queue position
+    ///
+    /// **[DBD-DEC-050]** Position → priority mapping: current = Immediate, next = Next,
+    /// everything else (queued/prefetch) = Prefetch
+    pub(super) async fn decode_priority_for_entry(&self, queue_entry_id: Uuid) -> DecodePriority {
+        let queue = self.queue.read().await;
+        if queue.current().map(|e| e.queue_entry_id) == Some(queue_entry_id) {
+            DecodePriority::Immediate
+        } else if queue.next().map(|e| e.queue_entry_id) == Some(queue_entry_id) {
+            DecodePriority::Next
+        } else {
+            DecodePriority::Prefetch
+        }
+    }
+
+    /// Record a chain assignment and the priority it was made under
+    async fn record_chain_assignment(&self, queue_entry_id: Uuid, chain_index: usize, priority: DecodePriority) {
+        let mut assignments = self.chain_assignments.write().await;
+        assignments.insert(queue_entry_id, chain_index);
+        drop(assignments);
+
+        let mut priorities = self.chain_priorities.write().await;
+        priorities.insert(queue_entry_id, priority);
+        drop(priorities);
+
+        debug!(
+            queue_entry_id = %queue_entry_id,
+            chain_index = chain_index,
+            priority = ?priority,
+            "Assigned decoder-buffer chain to passage"
+        );
+    }
+
+    /// Locate the best chain to preempt for a higher-priority request
+    ///
+    /// **[DBD-DEC-050]** Priority preemption
+    ///
+    /// Returns the `queue_entry_id` holding a chain under the lowest priority
+    /// (furthest from playback) among entries whose priority is strictly lower
+    /// than `requester_priority` - e.g. for an `Immediate` requester this prefers
+    /// a `Prefetch` victim, falling back to `Next` only if no `Prefetch` chain is
+    /// held. Entries of equal-or-higher priority are never returned.
+    async fn find_preemption_victim(&self, requester_priority: DecodePriority) -> Option<Uuid> {
+        let priorities = self.chain_priorities.read().await;
+        priorities
+            .iter()
+            .filter(|(_, &priority)| priority > requester_priority)
+            .max_by_key(|(_, &priority)| priority)
+            .map(|(&queue_entry_id, _)| queue_entry_id)
+    }
+
     /// Assign a decoder-buffer chain to a queue entry
     ///
     /// **[DBD-LIFECYCLE-020]** Chain assignment on queue entry addition
+    /// **[DBD-DEC-050]** Priority preemption when the pool is exhausted
     ///
     /// Assigns one of the N available decoder-buffer chains (N = maximum_decode_streams)
-    /// to the given queue entry. If all chains are in use, returns None.
+    /// to the given queue entry. If all chains are in use, attempts to preempt a
+    /// lower-priority chain (see `find_preemption_victim`) before giving up.
     ///
     /// # Returns
     /// * `Some(chain_index)` - Chain index assigned (0..maximum_decode_streams-1)
-    /// * `None` - No chains available (all maximum_decode_streams chains in use)
-    pub(super) async fn assign_chain(&self, queue_entry_id: Uuid) -> Option<usize> {
-        debug!("🔍 assign_chain: START for {}", queue_entry_id);
+    /// * `None` - No chains available, even after preemption
+    pub(super) async fn assign_chain(&self, queue_entry_id: Uuid, priority: DecodePriority) -> Option<usize> {
+        debug!("🔍 assign_chain: START for {} (priority: {:?})", queue_entry_id, priority);
         debug!("🔍 assign_chain: Acquiring available_chains write lock...");
         let mut available = self.available_chains.write().await;
         debug!("🔍 assign_chain: Acquired available_chains write lock, {} chains available", available.len());
         if let Some(Reverse(chain_index)) = available.pop() {
-            debug!("🔍 assign_chain: Popped chain_index {}, acquiring chain_assignments write lock...", chain_index);
-            let mut assignments = self.chain_assignments.write().await;
-            debug!("🔍 assign_chain: Acquired chain_assignments write lock");
-            assignments.insert(queue_entry_id, chain_index);
-            debug!(
-                queue_entry_id = %queue_entry_id,
-                chain_index = chain_index,
-                "Assigned decoder-buffer chain to passage"
-            );
+            drop(available);
+            self.record_chain_assignment(queue_entry_id, chain_index, priority).await;
             debug!("🔍 assign_chain: DONE - returning Some({})", chain_index);
-            Some(chain_index)
-        } else {
-            warn!(
+            return Some(chain_index);
+        }
+        drop(available);
+
+        // **[DBD-DEC-050]** Pool exhausted - try to preempt a lower-priority chain
+        // rather than let a stalled prefetch starve audible playback.
+        if let Some(victim_id) = self.find_preemption_victim(priority).await {
+            info!(
                 queue_entry_id = %queue_entry_id,
-                "No available chains for assignment (all {} chains in use)",
-                self.maximum_decode_streams
+                victim = %victim_id,
+                priority = ?priority,
+                "Preempting lower-priority chain for higher-priority decode request"
             );
-            debug!("🔍 assign_chain: DONE - returning None");
-            None
+            // release_chain cancels the victim's decode and returns its chain to the
+            // pool; the victim stays in the queue without a chain and will be picked
+            // back up by assign_chains_to_unassigned_entries() once one frees up.
+            self.release_chain(victim_id).await;
+
+            let mut available = self.available_chains.write().await;
+            if let Some(Reverse(chain_index)) = available.pop() {
+                drop(available);
+                self.record_chain_assignment(queue_entry_id, chain_index, priority).await;
+                debug!("🔍 assign_chain: DONE after preemption - returning Some({})", chain_index);
+                return Some(chain_index);
+            }
         }
+
+        warn!(
+            queue_entry_id = %queue_entry_id,
+            "No available chains for assignment (all {} chains in use)",
+            self.maximum_decode_streams
+        );
+        debug!("🔍 assign_chain: DONE - returning None");
+        None
     }
 
     /// Release a decoder-buffer chain from a queue entry
@@ -199,6 +270,9 @@ impl PlaybackEngine {
         }
         drop(assignments);
 
+        // **[DBD-DEC-050]** Clear the priority this chain was assigned under
+        self.chain_priorities.write().await.remove(&queue_entry_id);
+
         // **[DBD-DEC-045]** DO NOT call assign_chains_to_unassigned_entries() here!
         // Callers must ensure queue state is consistent before reassigning chains.
         // If called here, we may reassign to entries that are being removed.
@@ -248,7 +322,8 @@ impl PlaybackEngine {
 
         // Assign chains to unassigned entries (up to available chain limit)
         for queue_entry_id in unassigned_ids {
-            if self.assign_chain(queue_entry_id).await.is_some() {
+            let priority = self.decode_priority_for_entry(queue_entry_id).await;
+            if self.assign_chain(queue_entry_id, priority).await.is_some() {
                 info!("Assigned newly available chain to queue_entry={}", queue_entry_id);
                 // Note: Decode request will be submitted on next process_queue() tick
             } else {
@@ -276,4 +351,13 @@ impl PlaybackEngine {
             .map(|Reverse(idx)| *idx)
             .collect()
     }
+
+    /// Assign a chain under an explicit priority, bypassing queue-position inference
+    ///
+    /// **[TEST-HARNESS]** For testing only - exercises `assign_chain`'s preemption
+    /// logic directly without requiring a full queue/playback setup.
+    #[doc(hidden)]
+    pub async fn test_assign_chain(&self, queue_entry_id: Uuid, priority: DecodePriority) -> Option<usize> {
+        self.assign_chain(queue_entry_id, priority).await
+    }
 }
@@ -150,6 +150,11 @@ pub struct PlaybackEngine {
     /// Implements requirement that chains remain associated with passages throughout lifecycle
     pub(super) chain_assignments: Arc<RwLock<HashMap<Uuid, usize>>>,
 
+    /// Priority each chain was assigned under
+    /// **[DBD-DEC-050]** Tracked alongside `chain_assignments` so `assign_chain` can identify
+    /// a lower-priority victim to preempt when the chain pool is exhausted
+    pub(super) chain_priorities: Arc<RwLock<HashMap<Uuid, DecodePriority>>>,
+
     /// Available chain pool
     /// **[DBD-LIFECYCLE-030]** Min-heap for lowest-numbered chain allocation
     /// Chains are allocated in ascending order (0, 1, 2, ...) for visual consistency
@@ -165,6 +170,36 @@ pub struct PlaybackEngine {
     /// Set to true to force one immediate emission, then automatically reset
     pub(super) buffer_monitor_update_now: Arc<AtomicBool>,
 
+    /// Buffer monitor "auto" mode flag
+    /// **[SPEC020-MONITOR-140]** When true, emission interval adapts to the fill-level
+    /// trend instead of using the fixed `buffer_monitor_rate_ms` interval
+    pub(super) buffer_monitor_auto: Arc<AtomicBool>,
+
+    /// Recent (sampled_at, fill_level_percent) samples for the "auto" mode trend fit
+    /// **[SPEC020-MONITOR-140]** Short ring history used for the least-squares slope
+    pub(super) buffer_monitor_fill_history: Arc<Mutex<std::collections::VecDeque<(std::time::Instant, f64)>>>,
+
+    /// Buffer monitor "auto" mode thresholds (slope threshold, min/max interval)
+    /// **[SPEC020-MONITOR-140]** Loaded once from settings at construction
+    pub(super) buffer_monitor_auto_config: crate::db::settings::BufferMonitorAutoConfig,
+
+    /// Ring of the most recent mono downmixed post-mixer samples
+    /// **[SPEC020-SPECTRUM-010]** FFT tap for the output spectrum visualizer
+    /// Capacity is `spectrum_analyzer.fft_size()`; oldest samples drop off the front.
+    pub(super) spectrum_samples: Arc<Mutex<std::collections::VecDeque<f32>>>,
+
+    /// Precomputed FFT window table + plan for the spectrum visualizer
+    /// **[SPEC020-SPECTRUM-010]** Built once at engine construction (expensive to redo per frame)
+    pub(super) spectrum_analyzer: Arc<crate::playback::spectrum::SpectrumAnalyzer>,
+
+    /// Spectrum SSE emission rate (milliseconds)
+    /// **[SPEC020-SPECTRUM-010]** Same client-controlled rate pattern as `buffer_monitor_rate_ms`
+    pub(super) spectrum_rate_ms: Arc<RwLock<u64>>,
+
+    /// Force immediate spectrum emission
+    /// **[SPEC020-SPECTRUM-010]** Manual update trigger, mirrors `buffer_monitor_update_now`
+    pub(super) spectrum_update_now: Arc<AtomicBool>,
+
     /// Audio output buffer size in frames per callback
     /// **[DBD-PARAM-110]** Configurable audio buffer size (default: 512)
     pub(super) audio_buffer_size: u32,
@@ -195,7 +230,7 @@ impl PlaybackEngine {
 
         // **[PERF-INIT-010]** Parallel database queries for faster initialization
         let db_start = Instant::now();
-        let (initial_volume, min_buffer_threshold, interval_ms, grace_period_ms, mixer_config, maximum_decode_streams, resume_hysteresis, mixer_min_start_level, audio_buffer_size, buffer_capacity, buffer_headroom) = tokio::join!(
+        let (initial_volume, min_buffer_threshold, interval_ms, grace_period_ms, mixer_config, maximum_decode_streams, resume_hysteresis, mixer_min_start_level, audio_buffer_size, buffer_capacity, buffer_headroom, spectrum_fft_size, spectrum_bar_count, buffer_monitor_auto_config) = tokio::join!(
             crate::db::settings::get_volume(&db_pool),
             crate::db::settings::load_minimum_buffer_threshold(&db_pool),
             crate::db::settings::load_position_event_interval(&db_pool),
@@ -207,6 +242,9 @@ impl PlaybackEngine {
             crate::db::settings::load_audio_buffer_size(&db_pool), // [DBD-PARAM-110]
             crate::db::settings::load_playout_ringbuffer_capacity(&db_pool), // [DBD-PARAM-070]
             crate::db::settings::load_playout_ringbuffer_headroom(&db_pool), // [DBD-PARAM-080]
+            crate::db::settings::load_spectrum_fft_size(&db_pool), // [SPEC020-SPECTRUM-010]
+            crate::db::settings::load_spectrum_bar_count(&db_pool), // [SPEC020-SPECTRUM-010]
+            crate::db::settings::load_buffer_monitor_auto_config(&db_pool), // [SPEC020-MONITOR-140]
         );
         let db_elapsed = db_start.elapsed();
 
@@ -221,6 +259,9 @@ impl PlaybackEngine {
         let audio_buffer_size = audio_buffer_size?; // [DBD-PARAM-110]
         let buffer_capacity = buffer_capacity?; // [DBD-PARAM-070]
         let buffer_headroom = buffer_headroom?; // [DBD-PARAM-080]
+        let spectrum_fft_size = spectrum_fft_size?; // [SPEC020-SPECTRUM-010]
+        let spectrum_bar_count = spectrum_bar_count?; // [SPEC020-SPECTRUM-010]
+        let buffer_monitor_auto_config = buffer_monitor_auto_config?; // [SPEC020-MONITOR-140]
 
         info!(
             "⚡ Parallel config loaded in {:.2}ms: volume={:.2}, buffer_threshold={}ms, interval={}ms",
@@ -298,6 +339,16 @@ impl PlaybackEngine {
             maximum_decode_streams
         );
 
+        // **[SPEC020-SPECTRUM-010]** Precompute FFT window + plan once (expensive per-frame otherwise)
+        let spectrum_analyzer = Arc::new(crate::playback::spectrum::SpectrumAnalyzer::new(
+            spectrum_fft_size,
+            spectrum_bar_count,
+        ));
+        info!(
+            "Spectrum analyzer initialized: fft_size={}, bar_count={}",
+            spectrum_fft_size, spectrum_bar_count
+        );
+
         let total_elapsed = engine_start.elapsed();
         info!(
             "✅ Playback engine created in {:.2}ms",
@@ -323,9 +374,17 @@ impl PlaybackEngine {
             buffer_event_rx: Arc::new(RwLock::new(Some(buffer_event_rx))), // [PERF-POLL-010] Buffer event channel
             maximum_decode_streams, // [DBD-PARAM-050] Configurable decode stream limit
             chain_assignments: Arc::new(RwLock::new(HashMap::new())), // [DBD-LIFECYCLE-040] Track passage→chain mapping
+            chain_priorities: Arc::new(RwLock::new(HashMap::new())), // [DBD-DEC-050] Track priority each chain was assigned under
             available_chains: Arc::new(RwLock::new(available_chains_heap)), // [DBD-LIFECYCLE-030] Min-heap for lowest-first allocation
             buffer_monitor_rate_ms: Arc::new(RwLock::new(1000)), // [SPEC020-MONITOR-120] Default 1000ms update rate
             buffer_monitor_update_now: Arc::new(AtomicBool::new(false)), // [SPEC020-MONITOR-130] Manual update trigger
+            buffer_monitor_auto: Arc::new(AtomicBool::new(false)), // [SPEC020-MONITOR-140] Default to fixed-rate mode
+            buffer_monitor_fill_history: Arc::new(Mutex::new(std::collections::VecDeque::with_capacity(10))), // [SPEC020-MONITOR-140]
+            buffer_monitor_auto_config, // [SPEC020-MONITOR-140] Thresholds + interval bounds from settings
+            spectrum_samples: Arc::new(Mutex::new(std::collections::VecDeque::with_capacity(spectrum_fft_size))), // [SPEC020-SPECTRUM-010]
+            spectrum_analyzer, // [SPEC020-SPECTRUM-010] Precomputed window + FFT plan
+            spectrum_rate_ms: Arc::new(RwLock::new(1000)), // [SPEC020-SPECTRUM-010] Default 1000ms update rate
+            spectrum_update_now: Arc::new(AtomicBool::new(false)), // [SPEC020-SPECTRUM-010] Manual update trigger
             audio_buffer_size, // [DBD-PARAM-110] Configurable audio buffer size
             working_sample_rate, // [DBD-PARAM-020] Default 44.1kHz, updated when AudioOutput starts
             position_interval_ms: interval_ms, // [DEBT-004] Position marker interval from settings
@@ -381,6 +440,12 @@ impl PlaybackEngine {
             self_clone.buffer_chain_status_emitter().await;
         });
 
+        // **[SPEC020-SPECTRUM-010]** Start SpectrumUpdate emission task with client-controlled rate
+        let self_clone = self.clone_handles();
+        tokio::spawn(async move {
+            self_clone.spectrum_emitter().await;
+        });
+
         // Create lock-free ring buffer for audio frames
         // [SSD-OUT-012] Real-time audio callback requires lock-free operation
         // [ISSUE-1] Replaces try_write() pattern with lock-free ring buffer
@@ -415,6 +480,9 @@ impl PlaybackEngine {
         // [SUB-INC-4B] Clone additional variables for batch mixing
         let buffer_manager_clone = Arc::clone(&self.buffer_manager);
         let position_event_tx_clone = self.position_event_tx.clone();
+        // **[SPEC020-SPECTRUM-010]** Clone spectrum tap ring + its fixed capacity
+        let spectrum_samples_clone = Arc::clone(&self.spectrum_samples);
+        let spectrum_fft_size = self.spectrum_analyzer.fft_size();
         tokio::spawn(async move {
             info!("Mixer thread started");
             let mut check_interval = interval(Duration::from_micros(check_interval_us));
@@ -439,6 +507,8 @@ impl PlaybackEngine {
                 current_queue_entry_id: &mut Option<Uuid>,
                 _next_passage_id: &mut Option<Uuid>,
                 frames_to_mix: usize,
+                spectrum_samples: &Arc<Mutex<std::collections::VecDeque<f32>>>,
+                spectrum_fft_size: usize,
             ) {
                 // Allocate output buffer (stereo: 2 samples per frame)
                 let mut output = vec![0.0f32; frames_to_mix * 2];
@@ -457,6 +527,7 @@ impl PlaybackEngine {
                             break;
                         }
                     }
+                    push_spectrum_tap(spectrum_samples, spectrum_fft_size, &output);
                     return;
                 };
 
@@ -498,6 +569,24 @@ impl PlaybackEngine {
                         break;
                     }
                 }
+
+                push_spectrum_tap(spectrum_samples, spectrum_fft_size, &output);
+            }
+
+            // **[SPEC020-SPECTRUM-010]** Downmix stereo output to mono and feed the
+            // spectrum visualizer's fixed-capacity ring, dropping oldest samples.
+            fn push_spectrum_tap(
+                spectrum_samples: &Arc<Mutex<std::collections::VecDeque<f32>>>,
+                capacity: usize,
+                stereo_output: &[f32],
+            ) {
+                let mut ring = spectrum_samples.lock().unwrap();
+                for frame in stereo_output.chunks_exact(2) {
+                    if ring.len() >= capacity {
+                        ring.pop_front();
+                    }
+                    ring.push_back((frame[0] + frame[1]) * 0.5);
+                }
             }
 
             // [SUB-INC-4B] Convert MarkerEvents to PlaybackEvents
@@ -627,6 +716,8 @@ impl PlaybackEngine {
                         &mut current_queue_entry_id,
                         &mut next_passage_id,
                         frames_to_mix,
+                        &spectrum_samples_clone,
+                        spectrum_fft_size,
                     ).await;
                     // NO SLEEP - loop immediately to refill!
 
@@ -643,6 +734,8 @@ impl PlaybackEngine {
                         &mut current_queue_entry_id,
                         &mut next_passage_id,
                         frames_to_mix,
+                        &spectrum_samples_clone,
+                        spectrum_fft_size,
                     ).await;
 
                     // Minimal sleep when buffer is low
@@ -663,6 +756,8 @@ impl PlaybackEngine {
                         &mut current_queue_entry_id,
                         &mut next_passage_id,
                         frames_to_mix,
+                        &spectrum_samples_clone,
+                        spectrum_fft_size,
                     ).await;
                 } else {
                     // Buffer HIGH (> 75%) - just yield and wait for consumption
@@ -1035,9 +1130,17 @@ impl PlaybackEngine {
             buffer_event_rx: Arc::clone(&self.buffer_event_rx), // **[PERF-POLL-010]** Clone buffer event receiver
             maximum_decode_streams: self.maximum_decode_streams, // [DBD-PARAM-050] Copy decode stream limit
             chain_assignments: Arc::clone(&self.chain_assignments), // [DBD-LIFECYCLE-040] Clone chain assignment tracking
+            chain_priorities: Arc::clone(&self.chain_priorities), // [DBD-DEC-050] Clone chain priority tracking
             available_chains: Arc::clone(&self.available_chains), // [DBD-LIFECYCLE-030] Clone available chains pool
             buffer_monitor_rate_ms: Arc::clone(&self.buffer_monitor_rate_ms), // [SPEC020-MONITOR-120] Clone monitor rate
             buffer_monitor_update_now: Arc::clone(&self.buffer_monitor_update_now), // [SPEC020-MONITOR-130] Clone update trigger
+            buffer_monitor_auto: Arc::clone(&self.buffer_monitor_auto), // [SPEC020-MONITOR-140] Clone auto-mode flag
+            buffer_monitor_fill_history: Arc::clone(&self.buffer_monitor_fill_history), // [SPEC020-MONITOR-140] Clone trend history
+            buffer_monitor_auto_config: self.buffer_monitor_auto_config, // [SPEC020-MONITOR-140] Copy thresholds
+            spectrum_samples: Arc::clone(&self.spectrum_samples), // [SPEC020-SPECTRUM-010] Clone FFT tap ring
+            spectrum_analyzer: Arc::clone(&self.spectrum_analyzer), // [SPEC020-SPECTRUM-010] Clone precomputed FFT plan
+            spectrum_rate_ms: Arc::clone(&self.spectrum_rate_ms), // [SPEC020-SPECTRUM-010] Clone spectrum rate
+            spectrum_update_now: Arc::clone(&self.spectrum_update_now), // [SPEC020-SPECTRUM-010] Clone update trigger
             callback_monitor: Arc::clone(&self.callback_monitor), // Clone callback monitor for gap detection
             audio_buffer_size: self.audio_buffer_size, // [DBD-PARAM-110] Copy audio buffer size
             passage_start_time: Arc::clone(&self.passage_start_time), // [SUB-INC-4B] Clone passage start time tracking
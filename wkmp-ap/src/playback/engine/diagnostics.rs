@@ -416,12 +416,22 @@ impl PlaybackEngine {
     /// Set buffer chain monitor update rate
     ///
     /// **[SPEC020-MONITOR-120]** Client-controlled SSE emission rate
+    /// **[SPEC020-MONITOR-140]** `auto` mode adapts the interval to the fill-level trend
     ///
     /// # Arguments
-    /// * `rate_ms` - Update interval in milliseconds (100, 1000, or 0 for manual)
-    pub async fn set_buffer_monitor_rate(&self, rate_ms: u64) {
+    /// * `rate_ms` - Fixed update interval in milliseconds (100, 1000, or 0 for manual);
+    ///   ignored while `auto` is true
+    /// * `auto` - When true, emission rate adapts between the configured min/max interval
+    ///   based on how fast buffer fill level is trending, instead of using `rate_ms`
+    pub async fn set_buffer_monitor_rate(&self, rate_ms: u64, auto: bool) {
         *self.buffer_monitor_rate_ms.write().await = rate_ms;
-        info!("Buffer monitor rate set to: {}ms", rate_ms);
+        self.buffer_monitor_auto.store(auto, Ordering::Relaxed);
+        if auto {
+            // Fresh mode: discard stale trend history so the first auto emission
+            // doesn't fit a slope across the manual/auto mode boundary.
+            self.buffer_monitor_fill_history.lock().unwrap().clear();
+        }
+        info!("Buffer monitor rate set to: {}ms (auto={})", rate_ms, auto);
     }
 
     /// Trigger immediate buffer chain status update
@@ -434,6 +444,41 @@ impl PlaybackEngine {
         debug!("Buffer monitor update now triggered");
     }
 
+    /// Set spectrum visualizer update rate
+    ///
+    /// **[SPEC020-SPECTRUM-010]** Client-controlled SSE emission rate, same pattern
+    /// as `set_buffer_monitor_rate`.
+    ///
+    /// # Arguments
+    /// * `rate_ms` - Update interval in milliseconds (e.g. 100, 1000, or 0 for manual)
+    pub async fn set_spectrum_rate(&self, rate_ms: u64) {
+        *self.spectrum_rate_ms.write().await = rate_ms;
+        info!("Spectrum monitor rate set to: {}ms", rate_ms);
+    }
+
+    /// Trigger immediate spectrum update
+    ///
+    /// **[SPEC020-SPECTRUM-010]** Manual update trigger
+    ///
+    /// Forces one immediate SpectrumUpdate SSE emission, regardless of current mode.
+    pub fn trigger_spectrum_update(&self) {
+        self.spectrum_update_now.store(true, Ordering::Relaxed);
+        debug!("Spectrum update now triggered");
+    }
+
+    /// Compute current output spectrum magnitude bars on demand
+    ///
+    /// **[SPEC020-SPECTRUM-010]** Runs the precomputed FFT against the most
+    /// recently tapped post-mixer samples.
+    ///
+    /// # Returns
+    /// `None` when fewer than `spectrum_analyzer.fft_size()` samples have been
+    /// produced yet (e.g. right after playback starts).
+    pub async fn get_spectrum(&self, db_scale: bool) -> Option<Vec<f32>> {
+        let samples: Vec<f32> = self.spectrum_samples.lock().unwrap().iter().copied().collect();
+        self.spectrum_analyzer.analyze(&samples, db_scale)
+    }
+
     // ========================================================================
     // EVENT HANDLERS (internal, spawned by start())
     // ========================================================================
@@ -762,12 +807,61 @@ impl PlaybackEngine {
         }
     }
 
+    /// Record a (sampled_at, fill_percent) sample for the "auto" mode trend fit
+    ///
+    /// **[SPEC020-MONITOR-140]** Uses the now-playing chain's fill level
+    /// (`queue_position == Some(0)`); falls back to 0.0 when nothing is playing.
+    /// History is capped at 10 samples - enough to fit a short-term trend without
+    /// reacting to single-sample noise.
+    async fn record_buffer_monitor_fill_sample(&self, chains: &[wkmp_common::events::BufferChainInfo]) {
+        let fill_percent = chains
+            .iter()
+            .find(|c| c.queue_position == Some(0))
+            .map(|c| c.buffer_fill_percent as f64)
+            .unwrap_or(0.0);
+
+        let mut history = self.buffer_monitor_fill_history.lock().unwrap();
+        if history.len() >= 10 {
+            history.pop_front();
+        }
+        history.push_back((std::time::Instant::now(), fill_percent));
+    }
+
+    /// Compute the adaptive emission interval from the recorded fill-level trend
+    ///
+    /// **[SPEC020-MONITOR-140]** Fits a least-squares line over the fill-level
+    /// history (slope = percentage points/sec), then maps the slope magnitude
+    /// onto `[max_interval_ms, min_interval_ms]`: steady state (slope ~0) emits
+    /// at `max_interval_ms`, a slope at or beyond `slope_threshold_pct_per_sec`
+    /// emits at `min_interval_ms`.
+    fn auto_buffer_monitor_interval_ms(&self) -> u64 {
+        let config = self.buffer_monitor_auto_config;
+        let history = self.buffer_monitor_fill_history.lock().unwrap();
+
+        if history.len() < 2 {
+            return config.max_interval_ms;
+        }
+
+        let t0 = history[0].0;
+        let samples: Vec<(f64, f64)> = history
+            .iter()
+            .map(|(at, fill)| (at.duration_since(t0).as_secs_f64(), *fill))
+            .collect();
+        drop(history);
+
+        interval_ms_from_trend(&samples, config)
+    }
+
     /// Background task: Emit BufferChainStatus events at client-controlled rate
     ///
     /// **[SPEC020-MONITOR-120]** Client-controlled SSE emission rate
     /// **[SPEC020-MONITOR-130]** Manual update trigger support
+    /// **[SPEC020-MONITOR-140]** Trend-aware adaptive rate ("auto" mode)
     ///
-    /// The emission rate is controlled by `buffer_monitor_rate_ms`:
+    /// The emission rate is controlled by `buffer_monitor_rate_ms`, unless
+    /// `buffer_monitor_auto` is set, in which case the interval adapts between
+    /// the configured min/max bounds based on how fast the now-playing chain's
+    /// fill level is trending:
     /// - 100: Fast updates (10Hz) for visualizing rapid buffer filling
     /// - 1000: Normal updates (1Hz) for typical monitoring
     /// - 0: Manual mode (no automatic updates, only on update_now trigger)
@@ -794,18 +888,29 @@ impl PlaybackEngine {
 
             // Check current update rate
             let rate_ms = *self.buffer_monitor_rate_ms.read().await;
+            let auto = self.buffer_monitor_auto.load(Ordering::Relaxed);
             let update_now = self.buffer_monitor_update_now.swap(false, Ordering::Relaxed);
 
+            // **[SPEC020-MONITOR-140]** In auto mode, sample the trend every tick (not
+            // just on emission) so the slope fit reflects the true fill-level history.
+            let effective_rate_ms = if auto {
+                let chains = self.get_buffer_chains().await;
+                self.record_buffer_monitor_fill_sample(&chains).await;
+                self.auto_buffer_monitor_interval_ms()
+            } else {
+                rate_ms
+            };
+
             // Determine if we should emit
             let should_emit = if update_now {
                 // Manual "update now" trigger
                 true
-            } else if rate_ms == 0 {
+            } else if !auto && rate_ms == 0 {
                 // Manual mode - no automatic updates
                 false
             } else {
-                // Automatic mode - check if interval has elapsed
-                last_emission.elapsed().as_millis() >= rate_ms as u128
+                // Automatic mode (fixed or trend-adaptive) - check if interval has elapsed
+                last_emission.elapsed().as_millis() >= effective_rate_ms as u128
             };
 
             if should_emit {
@@ -843,6 +948,63 @@ impl PlaybackEngine {
         }
     }
 
+    /// Background task: Emit SpectrumUpdate events at client-controlled rate
+    ///
+    /// **[SPEC020-SPECTRUM-010]** Output spectrum SSE for frequency-bar visualization
+    ///
+    /// Mirrors `buffer_chain_status_emitter`'s client-controlled rate pattern:
+    /// - `spectrum_rate_ms`: 100 (fast), 1000 (normal), or 0 (manual/disabled)
+    /// - `spectrum_update_now`: forces one immediate emission regardless of mode
+    ///
+    /// Runs the FFT against the most recent `spectrum_analyzer.fft_size()` mono
+    /// samples tapped from the post-mixer output; skips emission until that many
+    /// samples have accumulated (e.g. right after playback starts).
+    pub(super) async fn spectrum_emitter(&self) {
+        use tokio::time::interval;
+        use std::time::Duration;
+
+        info!("SpectrumUpdate emitter started (client-controlled rate)");
+
+        let mut tick = interval(Duration::from_millis(10)); // Fast poll internal state (10ms)
+        let mut last_emission = std::time::Instant::now();
+
+        loop {
+            tick.tick().await;
+
+            if !*self.running.read().await {
+                info!("SpectrumUpdate emitter stopping");
+                break;
+            }
+
+            let rate_ms = *self.spectrum_rate_ms.read().await;
+            let update_now = self.spectrum_update_now.swap(false, Ordering::Relaxed);
+
+            let should_emit = if update_now {
+                true
+            } else if rate_ms == 0 {
+                false
+            } else {
+                last_emission.elapsed().as_millis() >= rate_ms as u128
+            };
+
+            if !should_emit {
+                continue;
+            }
+
+            let samples: Vec<f32> = self.spectrum_samples.lock().unwrap().iter().copied().collect();
+
+            const DB_SCALE: bool = true;
+            if let Some(bars) = self.spectrum_analyzer.analyze(&samples, DB_SCALE) {
+                self.state.broadcast_event(wkmp_common::events::WkmpEvent::SpectrumUpdate {
+                    timestamp: chrono::Utc::now(),
+                    bars,
+                    db_scale: DB_SCALE,
+                });
+                last_emission = std::time::Instant::now();
+            }
+        }
+    }
+
     /// Background task: Emit PlaybackPosition events every 1 second
     ///
     /// **[SSE-UI-030]** Playback Position Updates
@@ -888,3 +1050,116 @@ impl PlaybackEngine {
         }
     }
 }
+
+/// Least-squares slope of `(elapsed_secs, value)` samples
+///
+/// **[SPEC020-MONITOR-140]** Standard linear regression slope formula:
+/// `(n*sum(t*v) - sum(t)*sum(v)) / (n*sum(t^2) - sum(t)^2)`. Returns 0.0 for
+/// fewer than 2 samples or a degenerate (zero-variance) time axis.
+fn least_squares_slope(samples: &[(f64, f64)]) -> f64 {
+    let n = samples.len() as f64;
+    if n < 2.0 {
+        return 0.0;
+    }
+
+    let sum_t: f64 = samples.iter().map(|(t, _)| t).sum();
+    let sum_v: f64 = samples.iter().map(|(_, v)| v).sum();
+    let sum_tv: f64 = samples.iter().map(|(t, v)| t * v).sum();
+    let sum_tt: f64 = samples.iter().map(|(t, _)| t * t).sum();
+
+    let denominator = n * sum_tt - sum_t * sum_t;
+    if denominator.abs() < f64::EPSILON {
+        return 0.0;
+    }
+
+    (n * sum_tv - sum_t * sum_v) / denominator
+}
+
+/// Map a fill-level trend onto an emission interval within `config`'s bounds
+///
+/// **[SPEC020-MONITOR-140]** Fits `samples` with [`least_squares_slope`], then
+/// scales the slope magnitude onto `[max_interval_ms, min_interval_ms]`:
+/// steady state (slope ~0) maps to `max_interval_ms`, a slope at or beyond
+/// `slope_threshold_pct_per_sec` maps to `min_interval_ms`.
+fn interval_ms_from_trend(
+    samples: &[(f64, f64)],
+    config: crate::db::settings::BufferMonitorAutoConfig,
+) -> u64 {
+    let slope = least_squares_slope(samples).abs();
+    let normalized = (slope / config.slope_threshold_pct_per_sec).clamp(0.0, 1.0);
+
+    let min = config.min_interval_ms as f64;
+    let max = config.max_interval_ms as f64;
+    (max - (max - min) * normalized).round() as u64
+}
+
+#[cfg(test)]
+mod trend_tests {
+    use super::*;
+
+    fn config() -> crate::db::settings::BufferMonitorAutoConfig {
+        crate::db::settings::BufferMonitorAutoConfig {
+            slope_threshold_pct_per_sec: 5.0,
+            min_interval_ms: 100,
+            max_interval_ms: 1000,
+        }
+    }
+
+    #[test]
+    fn least_squares_slope_rising_trend() {
+        let samples = vec![(0.0, 0.0), (1.0, 5.0), (2.0, 10.0)];
+        assert!((least_squares_slope(&samples) - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn least_squares_slope_falling_trend() {
+        let samples = vec![(0.0, 10.0), (1.0, 5.0), (2.0, 0.0)];
+        assert!((least_squares_slope(&samples) - (-5.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn least_squares_slope_flat_trend() {
+        let samples = vec![(0.0, 50.0), (1.0, 50.0), (2.0, 50.0)];
+        assert_eq!(least_squares_slope(&samples), 0.0);
+    }
+
+    #[test]
+    fn least_squares_slope_degenerate_time_axis_is_zero() {
+        // All samples at the same timestamp: zero-variance time axis.
+        let samples = vec![(1.0, 0.0), (1.0, 100.0)];
+        assert_eq!(least_squares_slope(&samples), 0.0);
+    }
+
+    #[test]
+    fn least_squares_slope_fewer_than_two_samples_is_zero() {
+        assert_eq!(least_squares_slope(&[]), 0.0);
+        assert_eq!(least_squares_slope(&[(0.0, 42.0)]), 0.0);
+    }
+
+    #[test]
+    fn interval_from_trend_steady_state_uses_max_interval() {
+        let samples = vec![(0.0, 50.0), (1.0, 50.0), (2.0, 50.0)];
+        assert_eq!(interval_ms_from_trend(&samples, config()), 1000);
+    }
+
+    #[test]
+    fn interval_from_trend_at_threshold_uses_min_interval() {
+        // Slope exactly at slope_threshold_pct_per_sec (5.0 pct/sec).
+        let samples = vec![(0.0, 0.0), (1.0, 5.0), (2.0, 10.0)];
+        assert_eq!(interval_ms_from_trend(&samples, config()), 100);
+    }
+
+    #[test]
+    fn interval_from_trend_beyond_threshold_clamps_to_min_interval() {
+        let samples = vec![(0.0, 0.0), (1.0, 50.0), (2.0, 100.0)];
+        assert_eq!(interval_ms_from_trend(&samples, config()), 100);
+    }
+
+    #[test]
+    fn interval_from_trend_half_threshold_is_midpoint() {
+        // Slope at half the threshold (2.5 pct/sec) should land halfway
+        // between min and max interval.
+        let samples = vec![(0.0, 0.0), (1.0, 2.5), (2.0, 5.0)];
+        assert_eq!(interval_ms_from_trend(&samples, config()), 550);
+    }
+}
@@ -275,7 +275,8 @@ impl PlaybackEngine {
 
         // **[DBD-LIFECYCLE-010]** Assign decoder-buffer chain on enqueue if available
         // Implements requirement that chains are assigned immediately when passage is enqueued
-        self.assign_chain(queue_entry_id).await;
+        let priority = self.decode_priority_for_entry(queue_entry_id).await;
+        self.assign_chain(queue_entry_id, priority).await;
 
         Ok(queue_entry_id)
     }
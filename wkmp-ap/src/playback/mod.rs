@@ -20,6 +20,7 @@ pub mod playout_ring_buffer;
 pub mod queue_manager;
 pub mod ring_buffer;
 pub mod song_timeline;
+pub mod spectrum; // [SPEC020-SPECTRUM-010] Output FFT spectrum analysis for visualization
 pub mod types;
 
 // Re-exports for external use (tests, other modules)
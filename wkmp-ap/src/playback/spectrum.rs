@@ -0,0 +1,151 @@
+//! Output spectrum analysis for the real-time visualizer
+//!
+//! **[SPEC020-SPECTRUM-010]** Taps the most recent N post-mixer output frames,
+//! downmixes to mono, applies a Hann window, and runs a real-to-complex FFT to
+//! produce the downsampled magnitude bars carried by `SpectrumUpdate` events on
+//! the `/events` SSE stream and returned as a JSON snapshot by
+//! `GET /playback/spectrum`; `/playback/spectrum` is not itself an SSE stream.
+//! The window table and FFT plan are expensive to build, so both are
+//! precomputed once in [`SpectrumAnalyzer::new`] and reused for every emission.
+
+use realfft::{RealFftPlanner, RealToComplex};
+use std::sync::Arc;
+
+/// FFT machinery for a fixed window size.
+pub struct SpectrumAnalyzer {
+    fft_size: usize,
+    window: Vec<f32>,
+    r2c: Arc<dyn RealToComplex<f32>>,
+    bar_count: usize,
+}
+
+impl SpectrumAnalyzer {
+    /// Create a new analyzer for `fft_size` mono samples (should be a power of
+    /// two, e.g. 2048) producing `bar_count` downsampled output bars.
+    pub fn new(fft_size: usize, bar_count: usize) -> Self {
+        let window = hann_window(fft_size);
+        let r2c = RealFftPlanner::<f32>::new().plan_fft_forward(fft_size);
+
+        Self {
+            fft_size,
+            window,
+            r2c,
+            bar_count,
+        }
+    }
+
+    /// FFT window size this analyzer was built for.
+    pub fn fft_size(&self) -> usize {
+        self.fft_size
+    }
+
+    /// Compute downsampled magnitude bars from `samples` (mono, oldest first).
+    ///
+    /// Only the most recent `fft_size` samples are used. Returns `None` when
+    /// fewer than `fft_size` samples are available yet (e.g. playback just
+    /// started) or the FFT fails.
+    pub fn analyze(&self, samples: &[f32], db_scale: bool) -> Option<Vec<f32>> {
+        if samples.len() < self.fft_size {
+            return None;
+        }
+
+        let tail = &samples[samples.len() - self.fft_size..];
+        let mut windowed: Vec<f32> = tail
+            .iter()
+            .zip(&self.window)
+            .map(|(s, w)| s * w)
+            .collect();
+
+        let mut spectrum = self.r2c.make_output_vec();
+        self.r2c.process(&mut windowed, &mut spectrum).ok()?;
+
+        let magnitudes: Vec<f32> = spectrum
+            .iter()
+            .map(|c| (c.re * c.re + c.im * c.im).sqrt())
+            .collect();
+
+        Some(downsample_log_bars(&magnitudes, self.bar_count, db_scale))
+    }
+}
+
+/// Hann window table: `0.5 - 0.5*cos(2*pi*n / (N-1))`
+fn hann_window(n: usize) -> Vec<f32> {
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+
+    (0..n)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (n - 1) as f32).cos())
+        .collect()
+}
+
+/// Average `magnitudes` (linear FFT bins) into `bar_count` log-spaced buckets,
+/// optionally converting each bucket average to dB (`20*log10(mag+eps)`).
+///
+/// Log spacing gives bass frequencies (few Hz per bin) and treble (many Hz per
+/// bin) comparable visual resolution, matching how a frequency-bar visualizer
+/// is expected to look.
+fn downsample_log_bars(magnitudes: &[f32], bar_count: usize, db_scale: bool) -> Vec<f32> {
+    const EPS: f32 = 1e-9;
+
+    let bin_count = magnitudes.len();
+    if bin_count < 2 || bar_count == 0 {
+        return Vec::new();
+    }
+
+    // Skip bin 0 (DC component) - log-spacing starts at bin 1.
+    let log_min = 1.0_f32.ln();
+    let log_max = (bin_count as f32).ln();
+
+    let mut bars = Vec::with_capacity(bar_count);
+    for bar in 0..bar_count {
+        let lo = (log_min + (log_max - log_min) * bar as f32 / bar_count as f32).exp();
+        let hi = (log_min + (log_max - log_min) * (bar + 1) as f32 / bar_count as f32).exp();
+
+        let lo_bin = (lo as usize).clamp(1, bin_count - 1);
+        let hi_bin = (hi as usize).clamp(lo_bin + 1, bin_count);
+
+        let slice = &magnitudes[lo_bin..hi_bin];
+        let avg = slice.iter().sum::<f32>() / slice.len() as f32;
+
+        bars.push(if db_scale { 20.0 * (avg + EPS).log10() } else { avg });
+    }
+
+    bars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyze_requires_full_window() {
+        let analyzer = SpectrumAnalyzer::new(64, 8);
+        assert!(analyzer.analyze(&[0.0; 32], false).is_none());
+    }
+
+    #[test]
+    fn analyze_produces_requested_bar_count() {
+        let analyzer = SpectrumAnalyzer::new(64, 8);
+        let samples: Vec<f32> = (0..64)
+            .map(|i| (i as f32 * 0.1).sin())
+            .collect();
+        let bars = analyzer.analyze(&samples, false).expect("enough samples");
+        assert_eq!(bars.len(), 8);
+        assert!(bars.iter().all(|b| b.is_finite()));
+    }
+
+    #[test]
+    fn silence_yields_zero_magnitude_bars() {
+        let analyzer = SpectrumAnalyzer::new(64, 4);
+        let bars = analyzer.analyze(&[0.0; 64], false).expect("enough samples");
+        assert!(bars.iter().all(|&b| b.abs() < 1e-6));
+    }
+
+    #[test]
+    fn hann_window_endpoints_are_zero() {
+        let window = hann_window(8);
+        assert!(window[0].abs() < 1e-6);
+        assert!(window[7].abs() < 1e-6);
+    }
+}
@@ -76,6 +76,12 @@ pub struct TomlConfig {
     /// AcoustID API key for audio fingerprinting (optional)
     /// Used by: wkmp-ai (Audio Ingest) only
     pub acoustid_api_key: Option<String>,
+
+    /// Import passage repository storage backend (optional, default: "sqlite")
+    /// One of "sqlite", "memory", or "journaled:<path>"
+    /// Used by: wkmp-ai (Audio Ingest) only - resolved into a
+    /// `RepositoryBackend` by `import_v2::session_orchestrator::SessionOrchestrator::new`
+    pub import_repository_backend: Option<String>,
 }
 
 /// Logging configuration section
@@ -361,6 +361,15 @@ pub enum WkmpEvent {
         total_mixer_frames: u64,
         warnings: Vec<String>,
     },
+
+    /// Output spectrum update for frequency-bar visualization (client-controlled rate)
+    /// **[SPEC020-SPECTRUM-010]** FFT magnitude bars of the post-mixer output
+    SpectrumUpdate {
+        timestamp: chrono::DateTime<chrono::Utc>,
+        /// Downsampled magnitude bars (log-spaced bucket averages), in dB when `db_scale` is true
+        bars: Vec<f32>,
+        db_scale: bool,
+    },
 }
 
 /// Queue entry information for SSE events
@@ -570,6 +579,7 @@ impl WkmpEvent {
             WkmpEvent::ValidationSuccess { .. } => "ValidationSuccess",
             WkmpEvent::ValidationFailure { .. } => "ValidationFailure",
             WkmpEvent::ValidationWarning { .. } => "ValidationWarning",
+            WkmpEvent::SpectrumUpdate { .. } => "SpectrumUpdate",
         }
     }
 }
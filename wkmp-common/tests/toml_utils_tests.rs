@@ -27,6 +27,7 @@ fn test_atomic_write_creates_temp_file() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("key123".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -50,6 +51,7 @@ fn test_atomic_write_renames_to_target() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("key123".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -76,6 +78,7 @@ fn test_atomic_write_preserves_existing_fields() {
         logging: LoggingConfig::default(),
         static_assets: Some(PathBuf::from("/static")),
         acoustid_api_key: Some("key123".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -104,6 +107,7 @@ fn test_atomic_write_sets_permissions_0600() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("key123".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -127,6 +131,7 @@ fn test_atomic_write_graceful_on_windows() {
         logging: LoggingConfig::default(),
         static_assets: None,
         acoustid_api_key: Some("key123".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 
@@ -146,6 +151,7 @@ fn test_roundtrip_serialization_preserves_data() {
         logging: LoggingConfig::default(),
         static_assets: Some(PathBuf::from("/static")),
         acoustid_api_key: Some("key123".to_string()),
+        import_repository_backend: None,
         musicbrainz_token: None,
     };
 